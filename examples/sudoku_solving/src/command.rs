@@ -25,6 +25,54 @@ pub fn build_command() -> Command {
                 .action(clap::ArgAction::SetTrue)
                 .group("type"),
         )
-        .group(ArgGroup::new("type").required(true));
+        .arg(
+            Arg::new("killer")
+                .long("killer")
+                .help("Solve a Killer Sudoku")
+                .action(clap::ArgAction::SetTrue)
+                .group("type"),
+        )
+        .arg(
+            Arg::new("arrow")
+                .long("arrow")
+                .help("Solve an Arrow Sudoku")
+                .action(clap::ArgAction::SetTrue)
+                .group("type"),
+        )
+        .arg(
+            Arg::new("little-killer")
+                .long("little-killer")
+                .help("Solve a Little Killer Sudoku")
+                .action(clap::ArgAction::SetTrue)
+                .group("type"),
+        )
+        .arg(
+            Arg::new("sandwich")
+                .long("sandwich")
+                .help("Solve a Sandwich Sudoku")
+                .action(clap::ArgAction::SetTrue)
+                .group("type"),
+        )
+        .arg(
+            Arg::new("x-sums")
+                .long("x-sums")
+                .help("Solve an X-Sums Sudoku")
+                .action(clap::ArgAction::SetTrue)
+                .group("type"),
+        )
+        .arg(
+            Arg::new("skyscraper")
+                .long("skyscraper")
+                .help("Solve a Skyscraper Sudoku")
+                .action(clap::ArgAction::SetTrue)
+                .group("type"),
+        )
+        .group(ArgGroup::new("type").required(true))
+        .arg(
+            Arg::new("explain")
+                .long("explain")
+                .help("Print a step-by-step explanation of the solution")
+                .action(clap::ArgAction::SetTrue),
+        );
     command
 }
@@ -2,9 +2,12 @@ mod command;
 
 use crate::command::build_command;
 use std::time::Instant;
-use sudoku::solver::solve;
-use sudoku::visualization::ascii::{AsciiBoardPrinter, AsciiGroupPrinter};
-use sudoku::{Game, GameState};
+use sudoku::solver::{explain, rate, solve};
+use sudoku::visualization::ascii::{
+    AsciiArrowPrinter, AsciiBoardPrinter, AsciiCagePrinter, AsciiGroupPrinter,
+    AsciiLittleKillerPrinter, AsciiOutsidePrinter,
+};
+use sudoku::{Arrow, Cage, Game, GameState, LittleKiller, OutsideClue};
 use visitor::prelude::*;
 
 fn main() {
@@ -18,6 +21,18 @@ fn main() {
         GameState::new(Game::new_example_nonomino())
     } else if matches.get_flag("hypersudoku") {
         GameState::new(Game::new_example_hypersudoku())
+    } else if matches.get_flag("killer") {
+        GameState::new(Game::new_example_killer())
+    } else if matches.get_flag("arrow") {
+        GameState::new(Game::new_example_arrow())
+    } else if matches.get_flag("little-killer") {
+        GameState::new(Game::new_example_little_killer())
+    } else if matches.get_flag("sandwich") {
+        GameState::new(Game::new_example_sandwich())
+    } else if matches.get_flag("x-sums") {
+        GameState::new(Game::new_example_x_sums())
+    } else if matches.get_flag("skyscraper") {
+        GameState::new(Game::new_example_skyscraper())
     } else {
         unimplemented!()
     };
@@ -28,9 +43,31 @@ fn main() {
     println!("Groups:");
     game.accept(&group_visitor);
 
+    if game.game.constraints_of::<Cage>().next().is_some() {
+        println!("\nCages:");
+        game.accept(&AsciiCagePrinter::new());
+    }
+
+    if game.game.constraints_of::<Arrow>().next().is_some() {
+        println!("\nArrows:");
+        game.accept(&AsciiArrowPrinter::new());
+    }
+
+    if game.game.constraints_of::<LittleKiller>().next().is_some() {
+        println!("\nLittle killer clues:");
+        game.accept(&AsciiLittleKillerPrinter::new());
+    }
+
+    if game.game.constraints_of::<OutsideClue>().next().is_some() {
+        println!("\nOutside clues:");
+        game.accept(&AsciiOutsidePrinter::new());
+    }
+
     println!("\nInitial state:");
     game.accept(&board_visitor);
 
+    println!("\nDifficulty: {}", rate(&game));
+
     println!("\nSearching for solution ...");
     let now = Instant::now();
 
@@ -46,4 +83,9 @@ fn main() {
 
     let valid = solution.validate(false);
     println!("\nSolution valid: {}", if valid { "yes" } else { "no" });
+
+    if matches.get_flag("explain") {
+        println!("\nExplanation:");
+        print!("{}", explain(&game));
+    }
 }
@@ -1,14 +1,43 @@
+pub(crate) mod arrow;
+pub(crate) mod cage;
+pub(crate) mod chess;
+pub(crate) mod constraint;
+pub(crate) mod edge;
 mod game;
 mod game_state;
+mod house;
 mod indexbitset;
+pub(crate) mod inequality;
+mod layout_error;
+pub(crate) mod line;
+pub(crate) mod little_killer;
+pub(crate) mod non_consecutive;
+pub(crate) mod outside;
+pub(crate) mod parity;
 mod placement;
 pub mod prelude;
+mod skyscraper;
 mod state;
+pub(crate) mod thermometer;
 mod valuebitset;
 
+pub use arrow::Arrow;
+pub use cage::Cage;
+pub use chess::{AntiKing, AntiKnight};
+pub use constraint::Constraint;
+pub use edge::{Edge, Marker, NegativeMarker};
 pub use game::Game;
 pub use game_state::{CollectType, GameState};
+pub use house::{House, HouseKind};
 pub use indexbitset::IndexBitSet;
+pub use inequality::Inequality;
+pub use layout_error::LayoutError;
+pub use line::{Line, LineKind};
+pub use little_killer::{Diagonal, LittleKiller};
+pub use non_consecutive::NonConsecutive;
+pub use outside::{OutsideClue, OutsideKind, Side};
+pub use parity::{Parity, ParityCell};
 pub use placement::Placement;
 pub use state::State;
+pub use thermometer::Thermometer;
 pub use valuebitset::ValueBitSet;
@@ -0,0 +1,155 @@
+use crate::game::constraint::{retain, Constraint};
+use crate::game::prelude::*;
+use crate::solver::Technique;
+use crate::transform::{Transform, TransformError};
+use crate::Game;
+use std::rc::Rc;
+
+/// An arrow: the digits along its shaft add up to the digit in its circle.
+///
+/// Digits may repeat along the shaft unless a house forbids it.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Arrow {
+    /// The cell holding the sum.
+    pub circle: Index,
+    /// The cells of the shaft in order, starting next to the circle.
+    pub shaft: Vec<Index>,
+}
+
+impl Arrow {
+    /// Creates an arrow from its circle and the cells of its shaft.
+    ///
+    /// ## Panics
+    /// Panics if the shaft is empty.
+    pub fn new(circle: Index, shaft: Vec<Index>) -> Arrow {
+        assert!(!shaft.is_empty(), "An arrow needs a shaft");
+        Arrow { circle, shaft }
+    }
+}
+
+impl Constraint for Arrow {
+    fn cells(&self) -> IndexBitSet {
+        let mut cells = IndexBitSet::default().with_value(self.circle);
+        for &index in self.shaft.iter() {
+            cells.insert(index);
+        }
+        cells
+    }
+
+    fn check(&self, values: &[ValueOption]) -> bool {
+        let mut sum = 0;
+        let mut open = 0;
+        for &index in self.shaft.iter() {
+            match values[index as usize] {
+                Some(value) => sum += value.get() as usize,
+                None => open += 1,
+            }
+        }
+
+        // Every open cell of the shaft adds at least 1 and at most 9.
+        let (min, max) = (sum + open, sum + 9 * open);
+        match values[self.circle as usize] {
+            Some(value) => (min..=max).contains(&(value.get() as usize)),
+            None => min <= 9,
+        }
+    }
+
+    fn prune(&self, candidates: &mut [ValueBitSet]) {
+        let shaft: Vec<(usize, usize)> = self
+            .shaft
+            .iter()
+            .map(|&index| bounds(&candidates[index as usize]))
+            .collect();
+        let min: usize = shaft.iter().map(|&(low, _)| low).sum();
+        let max: usize = shaft.iter().map(|&(_, high)| high).sum();
+
+        // The circle lies within the range of the possible sums of the shaft ...
+        let circle = &mut candidates[self.circle as usize];
+        *circle = retain(circle, |digit| (min..=max).contains(&(digit as usize)));
+        let (low, high) = bounds(circle);
+
+        // ... and each cell of the shaft makes up the difference to the circle,
+        // given the possible sums of the rest of the shaft.
+        for (&index, &(own_low, own_high)) in self.shaft.iter().zip(shaft.iter()) {
+            let rest_min = min - own_low;
+            let rest_max = max - own_high;
+            let cell = &mut candidates[index as usize];
+            *cell = retain(cell, |digit| {
+                let digit = digit as usize;
+                digit + rest_min <= high && digit + rest_max >= low
+            });
+        }
+    }
+
+    fn technique(&self) -> Technique {
+        Technique::Arrow
+    }
+
+    fn describe(&self, game: &Game) -> String {
+        let shaft: Vec<_> = self
+            .shaft
+            .iter()
+            .map(|&index| game.cell_name(index))
+            .collect();
+        format!(
+            "the arrow {} sums to its circle {}",
+            shaft.join(", "),
+            game.cell_name(self.circle)
+        )
+    }
+
+    fn transform(&self, transform: &Transform) -> Result<Rc<dyn Constraint>, TransformError> {
+        transform.keep_digits()?;
+        let shaft = self.shaft.iter().map(|&index| transform.target(index));
+        Ok(Rc::new(Arrow::new(
+            transform.target(self.circle),
+            shaft.collect(),
+        )))
+    }
+}
+
+/// Returns the smallest and largest of the values.
+fn bounds(values: &ValueBitSet) -> (usize, usize) {
+    let digits: Vec<usize> = values.iter().map(|value| value.get() as usize).collect();
+    let min = digits.iter().copied().min().unwrap_or(10);
+    let max = digits.iter().copied().max().unwrap_or(0);
+    (min, max)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::constraint::testing::{all_candidates, filled};
+
+    #[test]
+    fn checks_shaft_sums() {
+        let arrow = Arrow::new(0, vec![1, 2, 3]);
+        let mut values = filled(&[]);
+        assert!(arrow.check(&values));
+
+        values[0] = Value::new(2);
+        assert!(!arrow.check(&values));
+
+        values[0] = Value::new(8);
+        values[1] = Value::new(7);
+        assert!(!arrow.check(&values));
+
+        values[1] = Value::new(3);
+        values[2] = Value::new(4);
+        assert!(arrow.check(&values));
+
+        values[3] = Value::new(2);
+        assert!(!arrow.check(&values));
+    }
+
+    #[test]
+    fn prunes_from_sum_bounds() {
+        let arrow = Arrow::new(0, vec![1, 2]);
+        let mut candidates = all_candidates();
+        candidates[1] = ValueBitSet::from(&[5u8, 6][..]);
+        arrow.prune(&mut candidates);
+
+        assert_eq!(candidates[0], ValueBitSet::from(&[6u8, 7, 8, 9][..]));
+        assert_eq!(candidates[2], ValueBitSet::from(&[1u8, 2, 3, 4][..]));
+    }
+}
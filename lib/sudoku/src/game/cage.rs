@@ -0,0 +1,237 @@
+use crate::game::constraint::Constraint;
+use crate::game::prelude::*;
+use crate::solver::Technique;
+use crate::transform::{Transform, TransformError};
+use crate::Game;
+use std::cmp::Ordering;
+use std::rc::Rc;
+
+/// A killer cage: its digits must not repeat and must add up to the sum.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Cage {
+    pub cells: IndexBitSet,
+    pub sum: u8,
+}
+
+impl Cage {
+    /// Creates a cage over the specified cells.
+    ///
+    /// ## Panics
+    /// Panics if the cage has no cells or more than nine, or if no distinct digits in its
+    /// cells can add up to the sum.
+    pub fn new(cells: IndexBitSet, sum: u8) -> Cage {
+        assert!(
+            (1..=9).contains(&cells.len()),
+            "A cage must have between one and nine cells"
+        );
+        assert!(
+            !combinations(cells.len(), sum as usize).is_empty(),
+            "The digits of a cage of {} cells cannot add up to {}",
+            cells.len(),
+            sum
+        );
+        Cage { cells, sum }
+    }
+
+    /// Returns the sets of distinct digits that add up to the sum of the cage,
+    /// e.g. `{1, 2, 4}` and nothing else for a cage of three cells with a sum of 7.
+    pub fn combinations(&self) -> Vec<ValueBitSet> {
+        combinations(self.cells.len(), self.sum as usize)
+    }
+}
+
+impl Constraint for Cage {
+    fn cells(&self) -> IndexBitSet {
+        self.cells
+    }
+
+    fn check(&self, values: &[ValueOption]) -> bool {
+        let mut used = ValueBitSet::default();
+        let mut sum = 0;
+        let mut open = 0;
+        for index in self.cells.iter() {
+            match values[index as usize] {
+                Some(value) if used.contains(value) => return false,
+                Some(value) => {
+                    used.insert(value);
+                    sum += value.get() as usize;
+                }
+                None => open += 1,
+            }
+        }
+
+        // The open cells take distinct digits that are not used yet.
+        let unused: Vec<usize> = (1..=9u8)
+            .filter(|&digit| !used.contains(Value::new(digit).unwrap()))
+            .map(|digit| digit as usize)
+            .collect();
+        let min: usize = unused.iter().take(open).sum();
+        let max: usize = unused.iter().rev().take(open).sum();
+        (sum + min..=sum + max).contains(&(self.sum as usize))
+    }
+
+    fn prune(&self, candidates: &mut [ValueBitSet]) {
+        prune_sum(&self.cells, self.sum as usize, candidates);
+    }
+
+    fn technique(&self) -> Technique {
+        Technique::CageCombination
+    }
+
+    fn describe(&self, game: &Game) -> String {
+        let cells: Vec<_> = self
+            .cells
+            .iter()
+            .map(|index| game.cell_name(index))
+            .collect();
+        format!("the cage {} sums to {}", cells.join(", "), self.sum)
+    }
+
+    fn transform(&self, transform: &Transform) -> Result<Rc<dyn Constraint>, TransformError> {
+        transform.keep_digits()?;
+        Ok(Rc::new(Cage::new(transform.cells(&self.cells), self.sum)))
+    }
+}
+
+/// Returns the sets of `size` distinct digits that add up to `sum`.
+fn combinations(size: usize, sum: usize) -> Vec<ValueBitSet> {
+    let mut combinations = Vec::new();
+    for mask in 0u16..(1 << 9) {
+        if mask.count_ones() as usize != size {
+            continue;
+        }
+
+        let digits: Vec<u8> = (1..=9u8).filter(|d| mask & (1 << (d - 1)) != 0).collect();
+        if digits.iter().map(|&d| d as usize).sum::<usize>() == sum {
+            combinations.push(ValueBitSet::from(digits.as_slice()));
+        }
+    }
+    combinations
+}
+
+/// Restricts the candidates of distinct cells that add up to `sum` to the digits that
+/// appear in at least one complete assignment.
+///
+/// Assigning the cells in order, a set of used digits determines both the number of cells
+/// assigned and their sum, so the assignments are explored over the 512 digit sets.
+pub(crate) fn prune_sum(cells: &IndexBitSet, sum: usize, candidates: &mut [ValueBitSet]) {
+    const SETS: usize = 1 << 9;
+
+    let cells: Vec<usize> = cells.iter().map(|index| index as usize).collect();
+    let fits: Vec<u16> = cells
+        .iter()
+        .map(|&index| mask(&candidates[index]))
+        .collect();
+    let size = cells.len();
+
+    // Whether the first cells can take exactly the digits of the set.
+    let mut reachable = [false; SETS];
+    reachable[0] = true;
+    for used in 0..SETS {
+        let assigned = used.count_ones() as usize;
+        if !reachable[used] || assigned >= size {
+            continue;
+        }
+        for digit in digits(fits[assigned] & !(used as u16)) {
+            reachable[used | 1 << digit] = true;
+        }
+    }
+
+    // Whether the remaining cells can be filled so that all digits add up to the sum.
+    let mut completes = [false; SETS];
+    for used in (0..SETS).rev() {
+        let assigned = used.count_ones() as usize;
+        completes[used] = match assigned.cmp(&size) {
+            Ordering::Greater => false,
+            Ordering::Equal => digits(used as u16).map(|digit| digit + 1).sum::<usize>() == sum,
+            Ordering::Less => {
+                digits(fits[assigned] & !(used as u16)).any(|digit| completes[used | 1 << digit])
+            }
+        };
+    }
+
+    let mut allowed = vec![0u16; size];
+    for used in 0..SETS {
+        let assigned = used.count_ones() as usize;
+        if !reachable[used] || assigned >= size {
+            continue;
+        }
+        for digit in digits(fits[assigned] & !(used as u16)) {
+            if completes[used | 1 << digit] {
+                allowed[assigned] |= 1 << digit;
+            }
+        }
+    }
+
+    for (&index, &allowed) in cells.iter().zip(allowed.iter()) {
+        let values: Vec<u8> = digits(allowed).map(|digit| digit as u8 + 1).collect();
+        candidates[index] = ValueBitSet::from(values.as_slice());
+    }
+}
+
+/// Converts values to a bit mask where bit `d - 1` stands for the digit `d`.
+fn mask(values: &ValueBitSet) -> u16 {
+    values
+        .iter()
+        .fold(0, |mask, value| mask | 1 << (value.get() - 1))
+}
+
+/// Returns the zero-based digits of a bit mask.
+fn digits(mask: u16) -> impl Iterator<Item = usize> {
+    (0..9).filter(move |digit| mask & (1 << digit) != 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::constraint::testing::{all_candidates, filled};
+
+    #[test]
+    fn lists_combinations() {
+        let cage = Cage::new(indexes!(0, 1, 2), 7);
+        assert_eq!(
+            cage.combinations(),
+            vec![ValueBitSet::from(&[1u8, 2, 4][..])]
+        );
+
+        let cage = Cage::new(indexes!(0, 1), 10);
+        assert_eq!(cage.combinations().len(), 4);
+    }
+
+    #[test]
+    fn checks_sums_and_repeats() {
+        let cage = Cage::new(indexes!(0, 1, 2), 7);
+        let mut values = filled(&[]);
+        assert!(cage.check(&values));
+
+        values[0] = Value::new(4);
+        assert!(cage.check(&values));
+
+        values[1] = Value::new(4);
+        assert!(!cage.check(&values));
+
+        values[1] = Value::new(8);
+        assert!(!cage.check(&values));
+
+        values[1] = Value::new(1);
+        values[2] = Value::new(2);
+        assert!(cage.check(&values));
+    }
+
+    #[test]
+    fn prunes_to_combinations() {
+        let cage = Cage::new(indexes!(0, 1), 4);
+        let mut candidates = all_candidates();
+        candidates[1] = ValueBitSet::from(&[1u8, 2][..]);
+        cage.prune(&mut candidates);
+
+        assert_eq!(candidates[0], ValueBitSet::from(&[3u8][..]));
+        assert_eq!(candidates[1], ValueBitSet::from(&[1u8][..]));
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot add up to 2")]
+    fn rejects_impossible_sums() {
+        Cage::new(indexes!(0, 1), 2);
+    }
+}
@@ -0,0 +1,166 @@
+use crate::game::constraint::Constraint;
+use crate::game::prelude::*;
+use crate::solver::Technique;
+use crate::transform::{Transform, TransformError};
+use crate::Game;
+use std::rc::Rc;
+
+/// The moves of a chess knight as (x, y) offsets.
+const KNIGHT: [(isize, isize); 8] = [
+    (1, 2),
+    (2, 1),
+    (2, -1),
+    (1, -2),
+    (-1, -2),
+    (-2, -1),
+    (-2, 1),
+    (-1, 2),
+];
+
+/// The diagonal moves of a chess king as (x, y) offsets. Its orthogonal moves stay within a
+/// row or column anyway.
+const KING: [(isize, isize); 4] = [(1, 1), (1, -1), (-1, -1), (-1, 1)];
+
+/// The anti-knight rule: equal digits must not be a chess knight's move apart.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct AntiKnight;
+
+/// The anti-king rule: equal digits must not be a chess king's move apart, i.e. in diagonally
+/// touching cells.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct AntiKing;
+
+impl Constraint for AntiKnight {
+    fn cells(&self) -> IndexBitSet {
+        all_cells()
+    }
+
+    fn check(&self, values: &[ValueOption]) -> bool {
+        check_moves(&KNIGHT, values)
+    }
+
+    fn prune(&self, candidates: &mut [ValueBitSet]) {
+        prune_moves(&KNIGHT, candidates)
+    }
+
+    fn technique(&self) -> Technique {
+        Technique::HiddenSingle
+    }
+
+    fn describe(&self, _game: &Game) -> String {
+        "equal digits cannot be a knight's move apart".to_string()
+    }
+
+    fn peers(&self, index: Index) -> IndexBitSet {
+        moves(&KNIGHT, index)
+    }
+
+    fn transform(&self, transform: &Transform) -> Result<Rc<dyn Constraint>, TransformError> {
+        transform.keep_neighborhoods()?;
+        Ok(Rc::new(AntiKnight))
+    }
+}
+
+impl Constraint for AntiKing {
+    fn cells(&self) -> IndexBitSet {
+        all_cells()
+    }
+
+    fn check(&self, values: &[ValueOption]) -> bool {
+        check_moves(&KING, values)
+    }
+
+    fn prune(&self, candidates: &mut [ValueBitSet]) {
+        prune_moves(&KING, candidates)
+    }
+
+    fn technique(&self) -> Technique {
+        Technique::HiddenSingle
+    }
+
+    fn describe(&self, _game: &Game) -> String {
+        "equal digits cannot be a king's move apart".to_string()
+    }
+
+    fn peers(&self, index: Index) -> IndexBitSet {
+        moves(&KING, index)
+    }
+
+    fn transform(&self, transform: &Transform) -> Result<Rc<dyn Constraint>, TransformError> {
+        transform.keep_neighborhoods()?;
+        Ok(Rc::new(AntiKing))
+    }
+}
+
+fn all_cells() -> IndexBitSet {
+    let mut cells = IndexBitSet::default();
+    for index in 0..81 {
+        cells.insert(index);
+    }
+    cells
+}
+
+/// Returns the cells one of the moves away from the specified cell.
+fn moves(offsets: &[(isize, isize)], index: Index) -> IndexBitSet {
+    let (x, y) = ((index % 9) as isize, (index / 9) as isize);
+    let mut cells = IndexBitSet::default();
+    for (dx, dy) in offsets {
+        let (x, y) = (x + dx, y + dy);
+        if (0..9).contains(&x) && (0..9).contains(&y) {
+            cells.insert((x + y * 9) as Index);
+        }
+    }
+    cells
+}
+
+/// Determines whether no digit repeats one of the moves away.
+fn check_moves(offsets: &[(isize, isize)], values: &[ValueOption]) -> bool {
+    (0..81).all(|index| {
+        let Some(value) = values[index as usize] else {
+            return true;
+        };
+        moves(offsets, index)
+            .iter()
+            .all(|peer| values[peer as usize] != Some(value))
+    })
+}
+
+/// Removes the digits of cells with a single candidate from the cells one of the moves away.
+fn prune_moves(offsets: &[(isize, isize)], candidates: &mut [ValueBitSet]) {
+    for index in 0..81 {
+        let single = candidates[index as usize];
+        if single.len() != 1 {
+            continue;
+        }
+
+        for peer in moves(offsets, index).iter() {
+            let cell = &mut candidates[peer as usize];
+            *cell = cell.without_all(&single);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::constraint::testing::{all_candidates, filled};
+
+    #[test]
+    fn forbids_repeats_a_move_apart() {
+        assert_eq!(AntiKnight.peers(0), IndexBitSet::from(&[11u8, 19][..]));
+        assert_eq!(AntiKing.peers(10).len(), 4);
+
+        let mut values = filled(&[(0, 5), (11, 6)]);
+        assert!(AntiKnight.check(&values));
+
+        values[19] = Value::new(5);
+        assert!(!AntiKnight.check(&values));
+        assert!(AntiKing.check(&values));
+
+        let mut candidates = all_candidates();
+        candidates[0] = ValueBitSet::from(&[5u8][..]);
+        AntiKing.prune(&mut candidates);
+        assert!(!candidates[10].contains(Value::new(5).unwrap()));
+        assert!(candidates[1].contains(Value::new(5).unwrap()));
+    }
+}
@@ -0,0 +1,182 @@
+use crate::game::prelude::*;
+use crate::solver::Technique;
+use crate::transform::{Transform, TransformError};
+use crate::Game;
+use std::any::Any;
+use std::rc::Rc;
+
+/// A rule that a solution must satisfy, such as a row, a group or a killer cage.
+///
+/// Constraints operate on the values and candidates of all cells, indexed by cell. A game holds
+/// all of its rules as constraints: the rows, columns and groups are built-in [`House`]
+/// constraints, followed by its variant clues. Custom rules are added through
+/// [`Game::add_constraint`](crate::Game::add_constraint) and are then respected by all solvers.
+///
+/// [`House`]: crate::House
+///
+/// ## Example
+///
+/// An X-Sudoku, in which the digits along each main diagonal are distinct:
+///
+/// ```
+/// # use sudoku::prelude::*;
+/// # use sudoku::solver::solve;
+/// # use sudoku::{Constraint, Game, GameState, IndexBitSet, ValueBitSet};
+/// struct Distinct(IndexBitSet);
+///
+/// impl Constraint for Distinct {
+///     fn cells(&self) -> IndexBitSet {
+///         self.0
+///     }
+///
+///     fn check(&self, values: &[ValueOption]) -> bool {
+///         let digits: Vec<ValueOption> = self.0.iter().map(|i| values[i as usize]).collect();
+///         let filled = digits.iter().filter(|value| value.is_some()).count();
+///         ValueBitSet::from(digits.as_slice()).len() == filled
+///     }
+///
+///     fn prune(&self, candidates: &mut [ValueBitSet]) {
+///         for index in self.0.iter() {
+///             let single = candidates[index as usize];
+///             if single.len() != 1 {
+///                 continue;
+///             }
+///             for other in self.0.iter().filter(|&other| other != index) {
+///                 candidates[other as usize] = candidates[other as usize].without_all(&single);
+///             }
+///         }
+///     }
+/// }
+///
+/// let diagonal: Vec<Index> = (0..9).map(|i| i * 10).collect();
+/// let anti_diagonal: Vec<Index> = (1..10).map(|i| i * 8).collect();
+///
+/// let mut game = Game::new_empty();
+/// game.add_constraint(Distinct(IndexBitSet::from(diagonal.as_slice())));
+/// game.add_constraint(Distinct(IndexBitSet::from(anti_diagonal.as_slice())));
+///
+/// let solved = solve(&GameState::new(game));
+/// assert!(solved.validate(false));
+/// let digits: Vec<ValueOption> = diagonal.iter().map(|&i| solved.state.values()[i as usize]).collect();
+/// assert_eq!(ValueBitSet::from(digits.as_slice()).len(), 9);
+/// ```
+pub trait Constraint: AsAny {
+    /// Returns the cells the constraint applies to.
+    fn cells(&self) -> IndexBitSet;
+
+    /// Determines whether the values satisfy the constraint as far as they are known,
+    /// i.e. whether the empty cells can still be filled in a way that satisfies it.
+    fn check(&self, values: &[ValueOption]) -> bool;
+
+    /// Removes the candidates that cannot satisfy the constraint. Filled cells are
+    /// represented by a single candidate.
+    fn prune(&self, candidates: &mut [ValueBitSet]);
+
+    /// Returns the technique credited with the eliminations of [`prune`](Self::prune).
+    fn technique(&self) -> Technique {
+        Technique::Custom
+    }
+
+    /// Describes the constraint, e.g. `cage r1c1, r1c2 (sum 3)`.
+    fn describe(&self, game: &Game) -> String {
+        let cells: Vec<_> = self
+            .cells()
+            .iter()
+            .map(|index| game.cell_name(index))
+            .collect();
+        format!("the constraint on {}", cells.join(", "))
+    }
+
+    /// Returns the cells that must not repeat the digit of the specified cell, e.g. the other
+    /// cells of a row. The solvers rule out the digits of filled cells in their peers directly.
+    fn peers(&self, _index: Index) -> IndexBitSet {
+        IndexBitSet::default()
+    }
+
+    /// Moves the constraint along with the board, as part of [`Transform::game`]. Fails if the
+    /// moved constraint would not be equivalent, e.g. if it depends on the digits and these are
+    /// relabeled. By default, constraints cannot be moved.
+    fn transform(&self, _transform: &Transform) -> Result<Rc<dyn Constraint>, TransformError> {
+        Err(TransformError::CustomConstraints)
+    }
+}
+
+/// Gives access to the concrete type behind a [`Constraint`], see
+/// [`Game::constraints_of`](crate::Game::constraints_of). Implemented for all types.
+pub trait AsAny {
+    fn as_any(&self) -> &dyn Any;
+}
+
+impl<T: Any> AsAny for T {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+/// Returns the values for which `keep` holds.
+pub(crate) fn retain<F: Fn(u8) -> bool>(values: &ValueBitSet, keep: F) -> ValueBitSet {
+    let digits: Vec<u8> = values
+        .iter()
+        .map(|value| value.get())
+        .filter(|&digit| keep(digit))
+        .collect();
+    ValueBitSet::from(digits.as_slice())
+}
+
+/// Fixtures shared by the tests of the constraints.
+#[cfg(test)]
+pub(crate) mod testing {
+    use crate::game::prelude::*;
+
+    /// Returns the candidates of a board on which every cell can take any digit.
+    pub(crate) fn all_candidates() -> [ValueBitSet; 81] {
+        [ValueBitSet::from(&[1u8, 2, 3, 4, 5, 6, 7, 8, 9][..]); 81]
+    }
+
+    /// Returns the values of a board on which only the specified cells are filled, given
+    /// as (cell, digit) pairs.
+    pub(crate) fn filled(digits: &[(usize, u8)]) -> [ValueOption; 81] {
+        let mut values = [None; 81];
+        for &(index, digit) in digits {
+            values[index] = Value::new(digit);
+        }
+        values
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::count_solutions;
+    use crate::GameState;
+
+    /// Forbids a digit in a cell.
+    struct Forbid(Index, u8);
+
+    impl Constraint for Forbid {
+        fn cells(&self) -> IndexBitSet {
+            IndexBitSet::default().with_value(self.0)
+        }
+
+        fn check(&self, values: &[ValueOption]) -> bool {
+            values[self.0 as usize] != Value::new(self.1)
+        }
+
+        fn prune(&self, candidates: &mut [ValueBitSet]) {
+            let cell = &mut candidates[self.0 as usize];
+            *cell = retain(cell, |digit| digit != self.1);
+        }
+    }
+
+    #[test]
+    fn custom_constraints_apply_to_the_search() {
+        // The example is uniquely solved with a 4 in r1c3.
+        let mut game = Game::new_example();
+        game.add_constraint(Forbid(3, 4));
+        assert_eq!(count_solutions(&GameState::new(game), 2), 1);
+
+        let mut game = Game::new_example();
+        game.add_constraint(Forbid(2, 4));
+        assert_eq!(count_solutions(&GameState::new(game), 2), 0);
+    }
+}
@@ -0,0 +1,259 @@
+use crate::game::constraint::{retain, Constraint};
+use crate::game::prelude::*;
+use crate::solver::Technique;
+use crate::transform::{Transform, TransformError};
+use crate::Game;
+use std::collections::HashSet;
+use std::rc::Rc;
+
+/// A marker on the edge between two orthogonally adjacent cells.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Marker {
+    /// A white Kropki dot: the digits are consecutive.
+    WhiteDot,
+    /// A black Kropki dot: one digit is double the other.
+    BlackDot,
+    /// A V: the digits add up to 5.
+    V,
+    /// An X: the digits add up to 10.
+    X,
+}
+
+impl Marker {
+    /// Determines whether a pair of digits satisfies the marker.
+    pub fn holds(&self, a: u8, b: u8) -> bool {
+        match self {
+            Marker::WhiteDot => a.abs_diff(b) == 1,
+            Marker::BlackDot => a == 2 * b || b == 2 * a,
+            Marker::V => a + b == 5,
+            Marker::X => a + b == 10,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Marker::WhiteDot => "white dot",
+            Marker::BlackDot => "black dot",
+            Marker::V => "V",
+            Marker::X => "X",
+        }
+    }
+
+    fn rule(&self) -> &'static str {
+        match self {
+            Marker::WhiteDot => "consecutive",
+            Marker::BlackDot => "in a 1:2 ratio",
+            Marker::V => "summing to 5",
+            Marker::X => "summing to 10",
+        }
+    }
+
+    fn technique(&self) -> Technique {
+        match self {
+            Marker::WhiteDot | Marker::BlackDot => Technique::Kropki,
+            Marker::V | Marker::X => Technique::XV,
+        }
+    }
+}
+
+/// A marker between two orthogonally adjacent cells whose digits must satisfy it.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Edge {
+    pub cells: (Index, Index),
+    pub marker: Marker,
+}
+
+impl Edge {
+    /// Creates a marker between two cells.
+    ///
+    /// ## Panics
+    /// Panics if the cells are not orthogonally adjacent on a 9x9 board.
+    pub fn new(a: Index, b: Index, marker: Marker) -> Edge {
+        assert!(
+            orthogonal_neighbors(a, 9, 9).contains(&b),
+            "The cells of an edge must be orthogonally adjacent"
+        );
+        Edge {
+            cells: (a.min(b), a.max(b)),
+            marker,
+        }
+    }
+}
+
+impl Constraint for Edge {
+    fn cells(&self) -> IndexBitSet {
+        IndexBitSet::default()
+            .with_value(self.cells.0)
+            .with_value(self.cells.1)
+    }
+
+    fn check(&self, values: &[ValueOption]) -> bool {
+        match (values[self.cells.0 as usize], values[self.cells.1 as usize]) {
+            (Some(a), Some(b)) => self.marker.holds(a.get(), b.get()),
+            _ => true,
+        }
+    }
+
+    fn prune(&self, candidates: &mut [ValueBitSet]) {
+        prune_pair(self.cells, candidates, |a, b| self.marker.holds(a, b));
+    }
+
+    fn technique(&self) -> Technique {
+        self.marker.technique()
+    }
+
+    fn describe(&self, game: &Game) -> String {
+        format!(
+            "the {} between {} and {} requires digits {}",
+            self.marker.name(),
+            game.cell_name(self.cells.0),
+            game.cell_name(self.cells.1),
+            self.marker.rule()
+        )
+    }
+
+    fn transform(&self, transform: &Transform) -> Result<Rc<dyn Constraint>, TransformError> {
+        transform.keep_digits()?;
+        let (a, b) = transform.adjacent(self.cells)?;
+        Ok(Rc::new(Edge::new(a, b, self.marker)))
+    }
+}
+
+/// The negative constraint of a marker: adjacent cells without any marker between them
+/// must not satisfy it.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct NegativeMarker {
+    marker: Marker,
+    /// The pairs of adjacent cells without a marker, the lower index first.
+    pairs: Vec<(Index, Index)>,
+}
+
+impl NegativeMarker {
+    /// Creates the negative constraint of a marker for the pairs of adjacent cells without any
+    /// of the specified edges between them.
+    pub fn new(marker: Marker, edges: &[Edge]) -> NegativeMarker {
+        let marked: HashSet<(Index, Index)> = edges.iter().map(|edge| edge.cells).collect();
+        let mut pairs = Vec::new();
+        for index in 0..81 {
+            for neighbor in orthogonal_neighbors(index, 9, 9) {
+                if index < neighbor && !marked.contains(&(index, neighbor)) {
+                    pairs.push((index, neighbor));
+                }
+            }
+        }
+        NegativeMarker { marker, pairs }
+    }
+
+    pub fn marker(&self) -> Marker {
+        self.marker
+    }
+}
+
+impl Constraint for NegativeMarker {
+    fn cells(&self) -> IndexBitSet {
+        let mut cells = IndexBitSet::default();
+        for &(a, b) in self.pairs.iter() {
+            cells.insert(a);
+            cells.insert(b);
+        }
+        cells
+    }
+
+    fn check(&self, values: &[ValueOption]) -> bool {
+        self.pairs
+            .iter()
+            .all(|&(a, b)| match (values[a as usize], values[b as usize]) {
+                (Some(a), Some(b)) => !self.marker.holds(a.get(), b.get()),
+                _ => true,
+            })
+    }
+
+    fn prune(&self, candidates: &mut [ValueBitSet]) {
+        for &pair in self.pairs.iter() {
+            prune_pair(pair, candidates, |a, b| !self.marker.holds(a, b));
+        }
+    }
+
+    fn technique(&self) -> Technique {
+        self.marker.technique()
+    }
+
+    fn describe(&self, _game: &Game) -> String {
+        format!(
+            "adjacent cells without a marker cannot hold digits {}",
+            self.marker.rule()
+        )
+    }
+
+    /// Moves the pairs of cells without a marker, which remain adjacent under the symmetries of
+    /// the square only.
+    fn transform(&self, transform: &Transform) -> Result<Rc<dyn Constraint>, TransformError> {
+        transform.keep_digits()?;
+        transform.keep_neighborhoods()?;
+        let pairs = self.pairs.iter().map(|&(a, b)| {
+            let (a, b) = (transform.target(a), transform.target(b));
+            (a.min(b), a.max(b))
+        });
+        Ok(Rc::new(NegativeMarker {
+            marker: self.marker,
+            pairs: pairs.collect(),
+        }))
+    }
+}
+
+/// Removes the candidates of either cell that no candidate of the other cell pairs up with.
+pub(crate) fn prune_pair<F: Fn(u8, u8) -> bool>(
+    (a, b): (Index, Index),
+    candidates: &mut [ValueBitSet],
+    allowed: F,
+) {
+    let (a, b) = (a as usize, b as usize);
+    let (first, second) = (candidates[a], candidates[b]);
+    candidates[a] = retain(&first, |x| second.iter().any(|y| allowed(x, y.get())));
+
+    let first = candidates[a];
+    candidates[b] = retain(&second, |y| first.iter().any(|x| allowed(x.get(), y)));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::constraint::testing::{all_candidates, filled};
+
+    #[test]
+    fn markers_hold() {
+        assert!(Marker::WhiteDot.holds(4, 5));
+        assert!(!Marker::WhiteDot.holds(4, 6));
+        assert!(Marker::BlackDot.holds(6, 3));
+        assert!(Marker::V.holds(1, 4));
+        assert!(Marker::X.holds(3, 7));
+    }
+
+    #[test]
+    fn prunes_marked_pairs() {
+        let edge = Edge::new(1, 0, Marker::BlackDot);
+        let mut candidates = all_candidates();
+        candidates[0] = ValueBitSet::from(&[3u8, 5][..]);
+        edge.prune(&mut candidates);
+
+        assert_eq!(candidates[0], ValueBitSet::from(&[3u8][..]));
+        assert_eq!(candidates[1], ValueBitSet::from(&[6u8][..]));
+    }
+
+    #[test]
+    fn negative_constraint_excludes_unmarked_pairs() {
+        let edges = [Edge::new(0, 1, Marker::V)];
+        let negative = NegativeMarker::new(Marker::V, &edges);
+        let mut values = filled(&[(0, 1), (1, 4)]);
+        assert!(negative.check(&values));
+
+        values[9] = Value::new(4);
+        assert!(!negative.check(&values));
+
+        let mut candidates = all_candidates();
+        candidates[0] = ValueBitSet::from(&[1u8][..]);
+        negative.prune(&mut candidates);
+        assert!(!candidates[9].contains(Value::new(4).unwrap()));
+        assert!(candidates[1].contains(Value::new(4).unwrap()));
+    }
+}
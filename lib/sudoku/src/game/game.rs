@@ -1,65 +1,120 @@
 // TODO: https://stackoverflow.com/questions/27673674/is-there-a-way-to-create-a-data-type-that-only-accepts-a-range-of-values
 // TODO: See https://docs.rs/array2d/0.2.1/array2d/
 
+use crate::game::arrow::Arrow;
+use crate::game::cage::Cage;
+use crate::game::chess::{AntiKing, AntiKnight};
+use crate::game::constraint::Constraint;
+use crate::game::edge::{Edge, Marker, NegativeMarker};
+use crate::game::house::{House, HouseKind};
 use crate::game::indexbitset::IndexBitSet;
+use crate::game::inequality::Inequality;
+use crate::game::layout_error::LayoutError;
+use crate::game::line::{Line, LineKind};
+use crate::game::little_killer::{Diagonal, LittleKiller};
+use crate::game::non_consecutive::NonConsecutive;
+use crate::game::outside::{OutsideClue, OutsideKind, Side};
+use crate::game::parity::{Parity, ParityCell};
+use crate::game::prelude::{cell_name, is_connected};
+use crate::game::thermometer::Thermometer;
 use crate::prelude::*;
 use crate::State;
-use std::collections::BTreeSet;
-use std::mem::MaybeUninit;
 use std::rc::Rc;
 use std::vec::Vec;
 use visitor::{AcceptVisitor, Visitor};
 
+#[derive(Clone)]
 pub struct Game {
     pub width: usize,
     pub height: usize,
     valid_symbols: [Value; 9],
     initial_state: State,
-    pub groups: Vec<Rc<IndexBitSet>>,
     group_lookup: [u8; 81],
+    /// All rules of the game: the rows, columns and groups as [`House`] constraints, followed
+    /// by the variant clues and custom constraints in the order they were added.
+    constraints: Vec<Rc<dyn Constraint>>,
+    /// The cells of the [`HouseKind::Group`] constraints, in order.
+    groups: Vec<Rc<IndexBitSet>>,
+    /// The cells that must not repeat the digit of each cell, gathered from the constraints.
+    peers: [IndexBitSet; 81],
+    /// Whether the regions are unknown and must be deduced along with the digits, as in a
+    /// chaos construction. Such a game has rows and columns, but no groups; see
+    /// [`solve_chaos`](crate::solver::solve_chaos).
+    chaos: bool,
 }
 
 impl Game {
     /// Initializes a standard Sudoku board from values in row-major order.
     pub fn new<S: IntoValues>(state: S) -> Game {
-        let symbols = build_default_symbols();
-        let groups = build_set_of_default_groups();
-        let group_lookup = build_default_index_to_group_lookup(&groups);
-        Game {
-            width: 9,
-            height: 9,
-            valid_symbols: symbols,
-            initial_state: State::new(state.into()),
-            groups,
-            group_lookup,
-        }
+        Game::new_with_groups(state, build_set_of_default_groups())
     }
 
+    /// Initializes a board with the specified group layout.
+    ///
+    /// ## Panics
+    /// Panics if the layout is invalid; see [`try_new_with_groups`](Self::try_new_with_groups).
     pub fn new_with_groups<S: IntoValues>(state: S, groups: Vec<Rc<IndexBitSet>>) -> Game {
-        let symbols = build_default_symbols();
-        let group_lookup = build_default_index_to_group_lookup(&groups);
-        Game {
-            width: 9,
-            height: 9,
-            valid_symbols: symbols,
-            initial_state: State::new(state.into()),
-            groups,
-            group_lookup,
+        match Game::try_new_with_groups(state, groups) {
+            Ok(game) => game,
+            Err(error) => panic!("Invalid group layout: {}", error),
         }
     }
 
+    /// Initializes a board with the specified group layout, validating the layout.
+    ///
+    /// Every group must consist of nine cells. The leading groups must tile the board into
+    /// connected, non-overlapping regions; any groups after the board is covered are additional
+    /// houses, such as the windows of a Hypersudoku, and may overlap the regions and each other.
+    pub fn try_new_with_groups<S: IntoValues>(
+        state: S,
+        groups: Vec<Rc<IndexBitSet>>,
+    ) -> Result<Game, LayoutError> {
+        let groups = groups
+            .iter()
+            .enumerate()
+            .map(|(id, group)| Rc::new(House::new(HouseKind::Group(id), **group)) as _);
+        let constraints = build_lines().chain(groups).collect();
+        Game::from_constraints(state.into(), constraints, false)
+    }
+
+    /// Initializes a chaos construction from values in row-major order: the board is to be
+    /// partitioned into nine connected regions of nine cells each, which are not given.
+    pub fn new_chaos<S: IntoValues>(state: S) -> Game {
+        Game::from_constraints(state.into(), build_lines().collect(), true)
+            .expect("The rows and columns need no validation")
+    }
+
     pub fn new_empty() -> Game {
-        let symbols = build_default_symbols();
-        let groups = build_set_of_default_groups();
-        let group_lookup = build_default_index_to_group_lookup(&groups);
-        Game {
+        Game::new([None; 81])
+    }
+
+    /// Builds a game from all of its constraints, validating the layout of its groups unless
+    /// it is a chaos construction.
+    pub(crate) fn from_constraints(
+        values: [ValueOption; 81],
+        constraints: Vec<Rc<dyn Constraint>>,
+        chaos: bool,
+    ) -> Result<Game, LayoutError> {
+        let mut game = Game {
             width: 9,
             height: 9,
-            valid_symbols: symbols,
-            initial_state: State::new([None; 81]),
-            groups,
-            group_lookup,
+            valid_symbols: build_default_symbols(),
+            initial_state: State::new(values),
+            group_lookup: [0; 81],
+            constraints: Vec::with_capacity(constraints.len()),
+            groups: Vec::new(),
+            peers: [IndexBitSet::default(); 81],
+            chaos,
+        };
+        for constraint in constraints {
+            game.push(constraint);
+        }
+
+        if !chaos {
+            validate_layout(&game.groups)?;
         }
+        game.group_lookup = build_default_index_to_group_lookup(&game.groups);
+        Ok(game)
     }
 
     #[rustfmt::skip]
@@ -109,6 +164,395 @@ impl Game {
         )
     }
 
+    /// A killer Sudoku without any givens.
+    pub fn new_example_killer() -> Game {
+        let mut game = Game::new_empty();
+        game.add_constraints([
+            Cage::new(indexes!(0, 9, 18), 12),
+            Cage::new(indexes!(1, 2), 7),
+            Cage::new(indexes!(3, 4), 13),
+            Cage::new(indexes!(5, 6, 15), 20),
+            Cage::new(indexes!(7, 16), 5),
+            Cage::new(indexes!(8), 2),
+            Cage::new(indexes!(10, 11, 12, 13), 19),
+            Cage::new(indexes!(14), 5),
+            Cage::new(indexes!(17, 26, 35), 18),
+            Cage::new(indexes!(19, 20), 17),
+            Cage::new(indexes!(21, 30, 39, 40), 23),
+            Cage::new(indexes!(22, 23, 32, 41), 10),
+            Cage::new(indexes!(24, 25, 34), 13),
+            Cage::new(indexes!(27, 36), 12),
+            Cage::new(indexes!(28, 37), 7),
+            Cage::new(indexes!(29, 38), 15),
+            Cage::new(indexes!(31), 6),
+            Cage::new(indexes!(33), 4),
+            Cage::new(indexes!(42, 50, 51), 19),
+            Cage::new(indexes!(43, 44, 52, 53), 21),
+            Cage::new(indexes!(45), 7),
+            Cage::new(indexes!(46, 55, 64, 65), 22),
+            Cage::new(indexes!(47, 48, 56), 13),
+            Cage::new(indexes!(49), 2),
+            Cage::new(indexes!(54, 63, 72), 14),
+            Cage::new(indexes!(57, 66, 67), 10),
+            Cage::new(indexes!(58, 59, 68, 77), 25),
+            Cage::new(indexes!(60, 61, 62), 14),
+            Cage::new(indexes!(69, 78, 79, 80), 23),
+            Cage::new(indexes!(70, 71), 8),
+            Cage::new(indexes!(73, 74), 9),
+            Cage::new(indexes!(75, 76), 10),
+        ]);
+        game
+    }
+
+    /// A thermometer Sudoku with few givens.
+    #[rustfmt::skip]
+    pub fn new_example_thermo() -> Game {
+        let x = 0u8;
+        let mut game = Game::new([
+            x, x, x, x, x, x, x, x, x,
+            x, x, x, x, x, x, x, x, x,
+            x, x, x, x, x, x, x, x, x,
+            x, x, x, x, 6, x, x, x, x,
+            x, x, x, x, x, x, x, x, 1,
+            x, x, x, x, x, x, 8, 5, x,
+            x, x, 1, x, x, x, x, x, x,
+            x, x, x, x, x, x, 6, 3, 5,
+            x, 4, 5, x, x, x, x, 7, x,
+        ]);
+        game.add_constraints([
+            Thermometer::new(vec![1, 0, 10]),
+            Thermometer::new(vec![8, 16, 26]),
+            Thermometer::new(vec![21, 31, 39, 48]),
+            Thermometer::new(vec![23, 15, 5, 6]),
+            Thermometer::new(vec![30, 20, 29]),
+            Thermometer::new(vec![32, 24, 25, 17]),
+            Thermometer::new(vec![36, 28, 19]),
+            Thermometer::new(vec![58, 57, 65, 64, 54]),
+            Thermometer::new(vec![60, 50, 59, 68]),
+        ]);
+        game
+    }
+
+    /// An arrow Sudoku with few givens.
+    #[rustfmt::skip]
+    pub fn new_example_arrow() -> Game {
+        let x = 0u8;
+        let mut game = Game::new([
+            x, 3, x, x, x, x, x, x, x,
+            x, x, x, x, x, x, x, x, x,
+            x, x, x, 3, x, x, x, x, x,
+            x, x, x, x, x, x, x, x, x,
+            4, x, x, x, x, x, x, 9, x,
+            x, 1, x, x, 2, x, x, x, x,
+            9, x, x, x, x, x, x, 8, x,
+            2, x, x, x, x, x, 6, x, x,
+            x, x, x, x, x, x, 1, x, x,
+        ]);
+        game.add_constraints([
+            Arrow::new(20, vec![21, 12, 22]),
+            Arrow::new(30, vec![31, 32]),
+            Arrow::new(35, vec![44, 34]),
+            Arrow::new(38, vec![28, 18]),
+            Arrow::new(57, vec![47, 37]),
+            Arrow::new(65, vec![75, 67, 66]),
+            Arrow::new(68, vec![59, 49]),
+            Arrow::new(71, vec![70, 60]),
+        ]);
+        game
+    }
+
+    /// A Kropki Sudoku with a single given. All dots are given: adjacent cells without
+    /// a dot are neither consecutive nor in a 1:2 ratio.
+    pub fn new_example_kropki() -> Game {
+        let mut values = [None; 81];
+        values[37] = Value::new(2);
+        let mut game = Game::new(values);
+        let edges = [
+            Edge::new(0, 9, Marker::WhiteDot),
+            Edge::new(1, 2, Marker::WhiteDot),
+            Edge::new(2, 11, Marker::BlackDot),
+            Edge::new(3, 4, Marker::WhiteDot),
+            Edge::new(4, 5, Marker::WhiteDot),
+            Edge::new(5, 6, Marker::WhiteDot),
+            Edge::new(7, 8, Marker::WhiteDot),
+            Edge::new(9, 10, Marker::WhiteDot),
+            Edge::new(11, 12, Marker::WhiteDot),
+            Edge::new(15, 16, Marker::WhiteDot),
+            Edge::new(16, 17, Marker::BlackDot),
+            Edge::new(17, 26, Marker::WhiteDot),
+            Edge::new(19, 20, Marker::WhiteDot),
+            Edge::new(20, 29, Marker::WhiteDot),
+            Edge::new(21, 22, Marker::WhiteDot),
+            Edge::new(22, 23, Marker::BlackDot),
+            Edge::new(23, 32, Marker::WhiteDot),
+            Edge::new(24, 25, Marker::WhiteDot),
+            Edge::new(24, 33, Marker::WhiteDot),
+            Edge::new(25, 26, Marker::WhiteDot),
+            Edge::new(27, 36, Marker::BlackDot),
+            Edge::new(30, 31, Marker::WhiteDot),
+            Edge::new(30, 39, Marker::WhiteDot),
+            Edge::new(31, 40, Marker::WhiteDot),
+            Edge::new(33, 34, Marker::BlackDot),
+            Edge::new(34, 35, Marker::WhiteDot),
+            Edge::new(36, 37, Marker::BlackDot),
+            Edge::new(37, 46, Marker::WhiteDot),
+            Edge::new(38, 47, Marker::BlackDot),
+            Edge::new(39, 48, Marker::WhiteDot),
+            Edge::new(41, 50, Marker::WhiteDot),
+            Edge::new(42, 51, Marker::WhiteDot),
+            Edge::new(49, 50, Marker::BlackDot),
+            Edge::new(49, 58, Marker::WhiteDot),
+            Edge::new(50, 51, Marker::BlackDot),
+            Edge::new(52, 53, Marker::WhiteDot),
+            Edge::new(57, 66, Marker::WhiteDot),
+            Edge::new(61, 62, Marker::BlackDot),
+            Edge::new(62, 71, Marker::WhiteDot),
+            Edge::new(63, 72, Marker::WhiteDot),
+            Edge::new(64, 65, Marker::WhiteDot),
+            Edge::new(64, 73, Marker::BlackDot),
+            Edge::new(66, 75, Marker::BlackDot),
+            Edge::new(69, 70, Marker::BlackDot),
+            Edge::new(72, 73, Marker::WhiteDot),
+            Edge::new(73, 74, Marker::WhiteDot),
+        ];
+        game.add_constraints(edges);
+        game.add_constraint(NegativeMarker::new(Marker::WhiteDot, &edges));
+        game.add_constraint(NegativeMarker::new(Marker::BlackDot, &edges));
+        game
+    }
+
+    /// An XV Sudoku with two givens. All markers are given: adjacent cells without
+    /// a marker do not add up to 5 or 10.
+    pub fn new_example_xv() -> Game {
+        let mut values = [None; 81];
+        values[20] = Value::new(8);
+        values[78] = Value::new(1);
+        let mut game = Game::new(values);
+        let edges = [
+            Edge::new(1, 10, Marker::X),
+            Edge::new(2, 3, Marker::X),
+            Edge::new(6, 7, Marker::X),
+            Edge::new(7, 16, Marker::V),
+            Edge::new(8, 17, Marker::X),
+            Edge::new(11, 20, Marker::X),
+            Edge::new(12, 13, Marker::X),
+            Edge::new(16, 25, Marker::X),
+            Edge::new(18, 19, Marker::X),
+            Edge::new(21, 30, Marker::X),
+            Edge::new(22, 31, Marker::X),
+            Edge::new(26, 35, Marker::X),
+            Edge::new(32, 33, Marker::V),
+            Edge::new(34, 35, Marker::V),
+            Edge::new(41, 42, Marker::X),
+            Edge::new(43, 44, Marker::X),
+            Edge::new(49, 58, Marker::V),
+            Edge::new(51, 60, Marker::X),
+            Edge::new(53, 62, Marker::X),
+            Edge::new(58, 59, Marker::X),
+            Edge::new(60, 61, Marker::X),
+            Edge::new(63, 64, Marker::X),
+            Edge::new(63, 72, Marker::V),
+            Edge::new(66, 67, Marker::V),
+            Edge::new(67, 68, Marker::X),
+            Edge::new(70, 79, Marker::X),
+            Edge::new(75, 76, Marker::X),
+        ];
+        game.add_constraints(edges);
+        game.add_constraint(NegativeMarker::new(Marker::V, &edges));
+        game.add_constraint(NegativeMarker::new(Marker::X, &edges));
+        game
+    }
+
+    /// An anti-knight Sudoku: equal digits must not be a knight's move apart.
+    pub fn new_example_anti_knight() -> Game {
+        let mut game = Game::new(
+            ".....3.....4......7..5..8............2..146......9...5.7......................5..",
+        );
+        game.add_constraint(AntiKnight);
+        game
+    }
+
+    /// An anti-king Sudoku: equal digits must not touch diagonally.
+    pub fn new_example_anti_king() -> Game {
+        let mut game = Game::new(
+            "..........96..812.5..7....3..2.............18.7.......9....2..4..1.8....8........",
+        );
+        game.add_constraint(AntiKing);
+        game
+    }
+
+    /// A non-consecutive Sudoku: orthogonally adjacent cells never hold consecutive digits.
+    pub fn new_example_non_consecutive() -> Game {
+        let mut game = Game::new(
+            "........8.......3...52....1.........6..9......1.......5.3.....................8.6",
+        );
+        game.add_constraint(NonConsecutive);
+        game
+    }
+
+    /// A greater-than Sudoku without givens: every pair of adjacent cells within a box
+    /// is marked with a `<` or `>` sign.
+    pub fn new_example_greater_than() -> Game {
+        // The signs as (greater, less) pairs of cells.
+        #[rustfmt::skip]
+        const SIGNS: [(Index, Index); 108] = [
+            (0, 1), (9, 0), (2, 1), (10, 1), (2, 11), (4, 3),
+            (3, 12), (5, 4), (13, 4), (5, 14), (6, 7), (6, 15),
+            (8, 7), (16, 7), (17, 8), (10, 9), (9, 18), (10, 11),
+            (19, 10), (20, 11), (13, 12), (21, 12), (13, 14), (13, 22),
+            (14, 23), (16, 15), (24, 15), (17, 16), (25, 16), (17, 26),
+            (19, 18), (19, 20), (22, 21), (22, 23), (25, 24), (26, 25),
+            (27, 28), (27, 36), (29, 28), (28, 37), (29, 38), (30, 31),
+            (39, 30), (31, 32), (31, 40), (41, 32), (33, 34), (42, 33),
+            (35, 34), (43, 34), (35, 44), (36, 37), (45, 36), (38, 37),
+            (37, 46), (38, 47), (39, 40), (48, 39), (40, 41), (40, 49),
+            (50, 41), (43, 42), (51, 42), (43, 44), (43, 52), (53, 44),
+            (45, 46), (47, 46), (48, 49), (50, 49), (51, 52), (53, 52),
+            (54, 55), (54, 63), (55, 56), (64, 55), (65, 56), (57, 58),
+            (57, 66), (59, 58), (58, 67), (68, 59), (61, 60), (69, 60),
+            (61, 62), (61, 70), (71, 62), (64, 63), (72, 63), (64, 65),
+            (64, 73), (65, 74), (66, 67), (66, 75), (68, 67), (76, 67),
+            (68, 77), (69, 70), (69, 78), (71, 70), (79, 70), (80, 71),
+            (73, 72), (74, 73), (76, 75), (76, 77), (79, 78), (80, 79),
+        ];
+
+        let mut game = Game::new_empty();
+        game.add_constraints(
+            SIGNS
+                .iter()
+                .map(|&(greater, less)| Inequality::new(greater, less)),
+        );
+        game
+    }
+
+    /// An odd/even Sudoku with shaded odd and even cells.
+    pub fn new_example_odd_even() -> Game {
+        let mut game = Game::new(
+            ".....89.......5.4.....4..6....7......2.8....17.39...............8.....3..4.......",
+        );
+        let odd = [6, 12, 18, 19, 29, 32, 35, 52, 58, 65, 70];
+        let even = [11, 38, 50, 55, 60, 63, 66, 69, 75];
+        game.add_constraints(
+            odd.iter()
+                .map(|&index| ParityCell::new(index, Parity::Odd))
+                .chain(
+                    even.iter()
+                        .map(|&index| ParityCell::new(index, Parity::Even)),
+                ),
+        );
+        game
+    }
+
+    /// A sandwich Sudoku: the clues give the sums of the digits between the 1 and the 9 of
+    /// their rows and columns.
+    pub fn new_example_sandwich() -> Game {
+        let mut game = Game::new(
+            "....7...........48...........9......4...............5.........4..........4......9",
+        );
+        let columns = [(1, 7), (3, 18), (4, 20), (5, 14), (6, 35), (7, 12), (8, 15)];
+        let rows = [(3, 13), (6, 6), (7, 0), (8, 7)];
+        let clue = |side, (line, sum)| OutsideClue::new(OutsideKind::Sandwich, side, line, sum);
+        game.add_constraints(
+            columns
+                .into_iter()
+                .map(|column| clue(Side::Top, column))
+                .chain(rows.into_iter().map(|row| clue(Side::Left, row))),
+        );
+        game
+    }
+
+    /// An X-sums Sudoku without givens: each clue gives the sum of the first X digits seen
+    /// from its side, where X is the first of these digits.
+    #[rustfmt::skip]
+    pub fn new_example_x_sums() -> Game {
+        let clues = [
+            (Side::Top, 0, 24), (Side::Top, 2, 23), (Side::Top, 3, 34), (Side::Top, 6, 45),
+            (Side::Top, 7, 1), (Side::Top, 8, 10),
+            (Side::Bottom, 1, 19), (Side::Bottom, 3, 6), (Side::Bottom, 5, 30),
+            (Side::Bottom, 6, 1), (Side::Bottom, 7, 40),
+            (Side::Left, 3, 42), (Side::Left, 5, 34), (Side::Left, 6, 45), (Side::Left, 7, 10),
+            (Side::Left, 8, 12),
+            (Side::Right, 2, 35), (Side::Right, 3, 9), (Side::Right, 5, 34), (Side::Right, 6, 21),
+            (Side::Right, 7, 24),
+        ];
+        let mut game = Game::new_empty();
+        game.add_constraints(
+            clues
+                .into_iter()
+                .map(|(side, line, sum)| OutsideClue::new(OutsideKind::XSum, side, line, sum)),
+        );
+        game
+    }
+
+    /// A Sudoku with German whispers, renban and palindrome lines.
+    #[rustfmt::skip]
+    pub fn new_example_lines() -> Game {
+        let lines = [
+            (LineKind::GermanWhispers, vec![78, 68, 60, 61]),
+            (LineKind::GermanWhispers, vec![11, 19, 18, 10]),
+            (LineKind::GermanWhispers, vec![21, 20, 12, 13, 23]),
+            (LineKind::GermanWhispers, vec![72, 64, 56, 48, 49, 59, 67]),
+            (LineKind::Renban, vec![50, 40, 30, 38]),
+            (LineKind::Renban, vec![71, 79, 69, 70, 62]),
+            (LineKind::Renban, vec![57, 58, 66]),
+            (LineKind::Palindrome, vec![73, 65, 55, 45, 36]),
+            (LineKind::Palindrome, vec![33, 24, 14, 22]),
+            (LineKind::Palindrome, vec![15, 25, 35]),
+        ];
+        let mut game = Game::new(
+            ".....8....72.................9......................5...1.....4..............61..",
+        );
+        game.add_constraints(
+            lines
+                .into_iter()
+                .map(|(kind, cells)| Line::new(kind, cells)),
+        );
+        game
+    }
+
+    /// A skyscraper Sudoku: each clue counts the digits visible from its side, taller digits
+    /// hiding the shorter ones behind them.
+    #[rustfmt::skip]
+    pub fn new_example_skyscraper() -> Game {
+        let clues = [
+            (Side::Top, 0, 4), (Side::Top, 1, 3), (Side::Top, 3, 4), (Side::Top, 6, 1),
+            (Side::Top, 8, 3),
+            (Side::Bottom, 0, 2), (Side::Bottom, 3, 4), (Side::Bottom, 5, 2), (Side::Bottom, 6, 4),
+            (Side::Bottom, 7, 3),
+            (Side::Left, 0, 5), (Side::Left, 1, 3), (Side::Left, 2, 2), (Side::Left, 4, 4),
+            (Side::Left, 6, 1), (Side::Left, 8, 5),
+            (Side::Right, 0, 2), (Side::Right, 6, 3), (Side::Right, 7, 3),
+        ];
+        let mut game = Game::new(
+            ".....8..2.........1...4..6......1.......5................................4.......",
+        );
+        game.add_constraints(
+            clues
+                .into_iter()
+                .map(|(side, line, count)| OutsideClue::new(OutsideKind::Skyscraper, side, line, count)),
+        );
+        game
+    }
+
+    /// A little killer Sudoku: the clues outside of the board give the sums along the
+    /// diagonals they point at.
+    pub fn new_example_little_killer() -> Game {
+        let mut game = Game::new(
+            ".....8............1........8........4..8........92..56......2...87..9.3.3........",
+        );
+        game.add_constraints([
+            LittleKiller::new(27, Diagonal::UpRight, 25),
+            LittleKiller::new(77, Diagonal::UpLeft, 25),
+            LittleKiller::new(8, Diagonal::DownLeft, 38),
+            LittleKiller::new(18, Diagonal::UpRight, 12),
+            LittleKiller::new(62, Diagonal::DownLeft, 8),
+            LittleKiller::new(45, Diagonal::DownRight, 22),
+            LittleKiller::new(26, Diagonal::UpLeft, 20),
+            LittleKiller::new(78, Diagonal::UpLeft, 34),
+        ]);
+        game
+    }
+
     #[rustfmt::skip]
     pub fn new_example_hypersudoku() -> Game {
         let mut index_set = Vec::new();
@@ -159,14 +603,125 @@ impl Game {
         self.group_lookup[index(x, y, self.width)]
     }
 
+    /// Returns the group at the specified cell.
+    ///
+    /// ## Panics
+    /// Panics for a [chaos](Self::is_chaos) game, as its groups are not known.
     pub fn group_at(&self, x: usize, y: usize) -> &IndexBitSet {
         let idx = self.group_id(x, y);
-        &self.groups[idx as usize]
+        self.groups
+            .get(idx as usize)
+            .expect("A chaos game has no groups")
+    }
+
+    /// Returns the groups of the game, i.e. its regions followed by any additional houses. The
+    /// groups of a [chaos](Self::is_chaos) game are not known and thus empty.
+    pub fn groups(&self) -> &[Rc<IndexBitSet>] {
+        &self.groups
+    }
+
+    /// Returns all groups containing the cell at the specified index.
+    pub fn groups_of(&self, index: Index) -> impl Iterator<Item = &IndexBitSet> {
+        self.constraints_of::<House>()
+            .filter(move |house| {
+                matches!(house.kind, HouseKind::Group(_)) && house.cells.contains(index)
+            })
+            .map(|house| &house.cells)
     }
 
     pub fn valid_symbols(&self) -> &[Value; 9] {
         &self.valid_symbols
     }
+
+    /// Returns all houses of the game, i.e. the rows, the columns and the groups.
+    pub fn houses(&self) -> Vec<House> {
+        self.constraints_of::<House>().copied().collect()
+    }
+
+    /// Determines whether the regions are unknown and must be deduced along with the digits, as
+    /// in a chaos construction.
+    pub fn is_chaos(&self) -> bool {
+        self.chaos
+    }
+
+    /// Returns all constraints of the game: its houses, followed by its variant clues and
+    /// custom constraints.
+    pub fn constraints(&self) -> &[Rc<dyn Constraint>] {
+        &self.constraints
+    }
+
+    /// Returns the constraints of the specified type, e.g. the [`Cage`]s of a killer Sudoku.
+    pub fn constraints_of<C: Constraint + 'static>(&self) -> impl Iterator<Item = &C> {
+        self.constraints
+            .iter()
+            .filter_map(|constraint| constraint.as_ref().as_any().downcast_ref::<C>())
+    }
+
+    /// Adds a constraint to the game, such as a killer cage or a custom rule. Solvers respect
+    /// all constraints of the game.
+    pub fn add_constraint<C: Constraint + 'static>(&mut self, constraint: C) {
+        self.push(Rc::new(constraint));
+    }
+
+    /// Adds each of the constraints to the game; see [`add_constraint`](Self::add_constraint).
+    pub fn add_constraints<C: Constraint + 'static, I: IntoIterator<Item = C>>(
+        &mut self,
+        constraints: I,
+    ) {
+        for constraint in constraints {
+            self.add_constraint(constraint);
+        }
+    }
+
+    /// Returns the cells that must not repeat the digit of the cell at the specified index,
+    /// such as the other cells of its houses.
+    pub fn peers(&self, index: Index) -> IndexBitSet {
+        self.peers[index as usize]
+    }
+
+    /// Returns a game with the same layout and constraints, but the specified clues.
+    pub fn with_clues<S: IntoValues>(&self, state: S) -> Game {
+        let mut game = self.clone();
+        game.initial_state = State::new(state.into());
+        game
+    }
+
+    /// Returns a game with the same clues and constraints, but the specified groups in place of
+    /// the unknown regions of a chaos construction.
+    pub(crate) fn with_groups(&self, groups: Vec<Rc<IndexBitSet>>) -> Game {
+        let mut game = Game::new_with_groups(*self.initial_state.values(), groups);
+        for constraint in self.constraints.iter() {
+            if constraint
+                .as_ref()
+                .as_any()
+                .downcast_ref::<House>()
+                .is_none()
+            {
+                game.push(constraint.clone());
+            }
+        }
+        game
+    }
+
+    /// Adds a constraint along with its peers and, for a group, its cells.
+    fn push(&mut self, constraint: Rc<dyn Constraint>) {
+        if let Some(house) = constraint.as_ref().as_any().downcast_ref::<House>() {
+            if let HouseKind::Group(_) = house.kind {
+                self.groups.push(Rc::new(house.cells));
+            }
+        }
+
+        for (index, peers) in self.peers.iter_mut().enumerate() {
+            peers.union(&constraint.peers(index as Index));
+        }
+        self.constraints.push(constraint);
+    }
+
+    /// Names the cell at the specified index in `r?c?` notation, e.g. `r1c3`
+    /// for the third cell of the first row.
+    pub fn cell_name(&self, index: Index) -> String {
+        cell_name(index, self.width)
+    }
 }
 
 impl AcceptVisitor<State> for Game {
@@ -190,6 +745,27 @@ fn build_default_symbols() -> [Value; 9] {
     ]
 }
 
+/// Builds the rows and columns of a standard board.
+fn build_lines() -> impl Iterator<Item = Rc<dyn Constraint>> {
+    let mut lines: Vec<Rc<dyn Constraint>> = Vec::with_capacity(18);
+    for y in 0..9 {
+        let mut cells = IndexBitSet::default();
+        for x in 0..9 {
+            cells.insert(index(x, y, 9) as _);
+        }
+        lines.push(Rc::new(House::new(HouseKind::Row(y), cells)));
+    }
+
+    for x in 0..9 {
+        let mut cells = IndexBitSet::default();
+        for y in 0..9 {
+            cells.insert(index(x, y, 9) as _);
+        }
+        lines.push(Rc::new(House::new(HouseKind::Column(x), cells)));
+    }
+    lines.into_iter()
+}
+
 /// Builds a default group rooted at the specified offsets.
 fn build_default_group(x_offset: usize, y_offset: usize) -> Rc<IndexBitSet> {
     let mut set = IndexBitSet::default();
@@ -217,36 +793,78 @@ fn build_set_of_default_groups() -> Vec<Rc<IndexBitSet>> {
     groups
 }
 
-fn groups_valid(groups: &Vec<Rc<IndexBitSet>>) -> bool {
-    let mut set = BTreeSet::<u8>::new();
-    for group in groups {
-        set.extend(group.iter());
+/// Validates that the groups tile the board, followed by any additional houses.
+fn validate_layout(groups: &[Rc<IndexBitSet>]) -> Result<(), LayoutError> {
+    let mut region_of: [Option<usize>; 81] = [None; 81];
+    let mut covered = 0;
+
+    for (id, group) in groups.iter().enumerate() {
+        if group.len() != 9 {
+            return Err(LayoutError::WrongCellCount {
+                group: id,
+                cells: group.len(),
+            });
+        }
+
+        if let Some(index) = group.iter().find(|&index| index as usize >= 81) {
+            return Err(LayoutError::OutOfBounds {
+                group: id,
+                index: index as usize,
+            });
+        }
+
+        // Once the board is covered, the remaining groups are additional houses.
+        if covered == 81 {
+            continue;
+        }
+
+        for index in group.iter() {
+            if let Some(other) = region_of[index as usize] {
+                return Err(LayoutError::Overlap {
+                    index,
+                    groups: (other, id),
+                });
+            }
+        }
+
+        if !is_connected(group, 9, 9) {
+            return Err(LayoutError::DisconnectedRegion(id));
+        }
+
+        for index in group.iter() {
+            region_of[index as usize] = Some(id);
+            covered += 1;
+        }
     }
-    set.len() == 81
-}
 
-/// Builds a reverse index of each cell to its group.
-fn build_default_index_to_group_lookup(groups: &Vec<Rc<IndexBitSet>>) -> [u8; 81] {
-    assert!(groups_valid(&groups));
+    match region_of.iter().position(|region| region.is_none()) {
+        Some(index) => Err(LayoutError::UncoveredCell(index as Index)),
+        None => Ok(()),
+    }
+}
 
-    let mut group_lookup: [MaybeUninit<u8>; 81] = unsafe { MaybeUninit::uninit().assume_init() };
+/// Builds a reverse index of each cell to its region, i.e. to the first group covering it.
+/// Additional houses after the regions, such as Hypersudoku windows, are not included.
+fn build_default_index_to_group_lookup(groups: &[Rc<IndexBitSet>]) -> [u8; 81] {
+    let mut group_lookup: [Option<u8>; 81] = [None; 81];
 
     debug_assert!(groups.len() < 81);
-    for gid in 0..groups.len() {
-        let group = &groups[gid];
+    for (gid, group) in groups.iter().enumerate() {
         for index in group.iter() {
-            group_lookup[index as usize] = MaybeUninit::new(gid as u8);
+            group_lookup[index as usize].get_or_insert(gid as u8);
         }
     }
 
-    unsafe { std::mem::transmute::<_, [u8; 81]>(group_lookup) }
+    group_lookup.map(|gid| gid.unwrap_or_default())
 }
 
 #[cfg(test)]
 mod tests {
     use crate::game::game::build_default_group;
+    use crate::game::{AntiKing, AntiKnight, LayoutError};
     use crate::prelude::*;
     use std::mem::MaybeUninit;
+    use std::rc::Rc;
 
     fn create_matrix() -> [ValueOption; 81] {
         let mut array: [MaybeUninit<ValueOption>; 81] =
@@ -297,6 +915,93 @@ mod tests {
         }
     }
 
+    #[test]
+    fn construction_from_str_works() {
+        let board = crate::Game::new(
+            "53..7....6..195....98....6.8...6...34..8.3..17...2...6.6....28....419..5....8..79",
+        );
+
+        assert_eq!(board.cell(0, 0), Value::try_from(5).ok());
+        assert_eq!(board.cell(2, 0), None);
+        assert_eq!(board.cell(8, 8), Value::try_from(9).ok());
+    }
+
+    #[test]
+    #[should_panic(expected = "A board has 81 cells, but more were given")]
+    fn rejects_strings_beyond_the_board() {
+        crate::Game::new(".".repeat(82).as_str());
+    }
+
+    #[test]
+    #[should_panic(expected = "A board has 81 cells, but 80 were given")]
+    fn rejects_strings_short_of_the_board() {
+        crate::Game::new(".".repeat(80).as_str());
+    }
+
+    #[test]
+    fn accepts_valid_layouts() {
+        for game in [
+            crate::Game::new_example_nonomino(),
+            crate::Game::new_example_hypersudoku(),
+        ] {
+            assert!(crate::Game::try_new_with_groups([None; 81], game.groups().to_vec()).is_ok());
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_layouts() {
+        let boxes = crate::Game::new_empty().groups().to_vec();
+
+        let mut short = boxes.clone();
+        short[0] = Rc::new(short[0].without_value(0));
+        assert_eq!(
+            crate::Game::try_new_with_groups([None; 81], short).err(),
+            Some(LayoutError::WrongCellCount { group: 0, cells: 8 })
+        );
+
+        let missing = boxes[..8].to_vec();
+        assert_eq!(
+            crate::Game::try_new_with_groups([None; 81], missing).err(),
+            Some(LayoutError::UncoveredCell(60))
+        );
+
+        // Swaps r1c3 and r1c4 between the first two boxes.
+        let mut overlapping = boxes.clone();
+        overlapping[1] = Rc::new(boxes[1].without_value(3).with_value(2));
+        assert_eq!(
+            crate::Game::try_new_with_groups([None; 81], overlapping).err(),
+            Some(LayoutError::Overlap {
+                index: 2,
+                groups: (0, 1)
+            })
+        );
+
+        let mut disconnected = boxes.clone();
+        disconnected[0] = Rc::new(boxes[0].without_value(20).with_value(30));
+        disconnected[4] = Rc::new(boxes[4].without_value(30).with_value(20));
+        assert_eq!(
+            crate::Game::try_new_with_groups([None; 81], disconnected).err(),
+            Some(LayoutError::DisconnectedRegion(0))
+        );
+    }
+
+    #[test]
+    fn accepts_additional_houses_sharing_cells() {
+        // The main diagonals of an X-Sudoku cross at r5c5.
+        let mut diagonals = crate::Game::new_empty().groups().to_vec();
+        diagonals.push(Rc::new(indexes!(0, 10, 20, 30, 40, 50, 60, 70, 80)));
+        diagonals.push(Rc::new(indexes!(8, 16, 24, 32, 40, 48, 56, 64, 72)));
+        let game = crate::Game::try_new_with_groups([None; 81], diagonals).unwrap();
+        assert_eq!(game.groups_of(40).count(), 3);
+    }
+
+    #[test]
+    fn group_lookup_ignores_additional_houses() {
+        let game = crate::Game::new_example_hypersudoku();
+        assert_eq!(game.group_id(1, 1), 0);
+        assert_eq!(game.group_id(3, 1), 1);
+    }
+
     #[test]
     fn group_lookup_works() {
         let board = crate::Game::new(create_matrix());
@@ -308,6 +1013,29 @@ mod tests {
             assert!(group.contains(index(5, y, 9) as _));
         }
     }
+
+    #[test]
+    fn peers_include_chess_moves() {
+        let mut game = crate::Game::new_empty();
+        let houses = game.peers(40);
+        assert_eq!(houses.len(), 20);
+
+        game.add_constraint(AntiKnight);
+        assert_eq!(
+            game.peers(40).without_all(&houses),
+            indexes!(21, 23, 29, 33, 47, 51, 57, 59)
+        );
+
+        assert!(!game.peers(20).contains(30));
+        game.add_constraint(AntiKing);
+        assert!(game.peers(20).contains(30));
+
+        let mut values = [None; 81];
+        values[0] = Value::new(1);
+        values[11] = Value::new(1);
+        let state = crate::GameState::new(game.with_clues(values));
+        assert!(!state.validate(true));
+    }
 }
 
 pub trait IntoValues {
@@ -332,3 +1060,27 @@ impl IntoValues for [ValueOption; 81] {
         self
     }
 }
+
+/// Parses values in row-major order, e.g. `"53..7...."`. Digits denote values, `.` or `0` denote
+/// empty cells and whitespace is ignored.
+///
+/// ## Panics
+/// Panics if a character is neither a digit, a `.` nor whitespace, or if the string does not
+/// describe exactly 81 cells.
+impl IntoValues for &str {
+    fn into(self) -> [ValueOption; 81] {
+        let mut values = [None; 81];
+        let mut count = 0;
+        for c in self.chars().filter(|c| !c.is_whitespace()) {
+            assert!(count < 81, "A board has 81 cells, but more were given");
+            values[count] = match c {
+                '.' | '0' => None,
+                '1'..='9' => Value::new(c as u8 - b'0'),
+                _ => panic!("Invalid cell value: {}", c),
+            };
+            count += 1;
+        }
+        assert_eq!(count, 81, "A board has 81 cells, but {} were given", count);
+        values
+    }
+}
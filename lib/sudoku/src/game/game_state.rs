@@ -1,7 +1,7 @@
 use crate::game::indexbitset::IndexBitSet;
 use crate::game::prelude::*;
 use crate::game::state::StateId;
-use crate::game::{Placement, ValueBitSet};
+use crate::game::Placement;
 use crate::{Game, State};
 use std::collections::HashSet;
 use std::hash::{Hash, Hasher};
@@ -52,10 +52,11 @@ impl GameState {
         y: Coordinate,
         exclude_self: bool,
     ) -> HashSet<Placement> {
-        let column = self.get_column_values(x, y, exclude_self);
-        let row = self.get_row_values(x, y, exclude_self);
-        let group = self.get_group_values(x, y, exclude_self);
-        join_hashset!(column, row, group)
+        let mut set = Vec::new();
+        for index in self.peer_cells(x, y, exclude_self).iter() {
+            self.collect_if_set(&mut set, index);
+        }
+        set.into_iter().collect()
     }
 
     pub fn peer_indexes_by_xy(
@@ -65,10 +66,11 @@ impl GameState {
         exclude_self: bool,
         how: CollectType,
     ) -> HashSet<Index> {
-        let column = self.get_column_indexes(x, y, exclude_self, &how);
-        let row = self.get_row_indexes(x, y, exclude_self, &how);
-        let group = self.get_group_indexes(x, y, exclude_self, &how);
-        join_hashset!(column, row, group)
+        let mut set = Vec::new();
+        for index in self.peer_cells(x, y, exclude_self).iter() {
+            self.collect_index_if(&mut set, index, &how);
+        }
+        set.into_iter().collect()
     }
 
     pub fn apply(&mut self, index: u8, value: Value) {
@@ -106,114 +108,15 @@ impl GameState {
         &self.state.id
     }
 
-    fn get_row_values(
-        &self,
-        x_reference: Coordinate,
-        y: Coordinate,
-        exclude_self: bool,
-    ) -> Vec<Placement> {
-        let mut set = Vec::new();
-        for x in 0..self.game.width {
-            if exclude_self && (x == x_reference) {
-                continue;
-            }
-
-            let index = self.xy_to_index(x, y);
-            self.collect_if_set(&mut set, index);
-        }
-        set
-    }
-
-    fn get_row_indexes(
-        &self,
-        x_reference: Coordinate,
-        y: Coordinate,
-        exclude_self: bool,
-        how: &CollectType,
-    ) -> Vec<Index> {
-        let mut set = Vec::new();
-        for x in 0..self.game.width {
-            if exclude_self && (x == x_reference) {
-                continue;
-            }
-
-            let index = self.xy_to_index(x, y);
-            self.collect_index_if(&mut set, index, &how);
-        }
-        set
-    }
-
-    fn get_column_values(
-        &self,
-        x: Coordinate,
-        y_reference: Coordinate,
-        exclude_self: bool,
-    ) -> Vec<Placement> {
-        let mut set = Vec::new();
-        for y in 0..self.game.height {
-            if exclude_self && (y == y_reference) {
-                continue;
-            }
-
-            let index = self.xy_to_index(x, y);
-            self.collect_if_set(&mut set, index);
-        }
-        set
-    }
-
-    fn get_column_indexes(
-        &self,
-        x: Coordinate,
-        y_reference: Coordinate,
-        exclude_self: bool,
-        how: &CollectType,
-    ) -> Vec<Index> {
-        let mut set = Vec::new();
-        for y in 0..self.game.height {
-            if exclude_self && (y == y_reference) {
-                continue;
-            }
-
-            let index = self.xy_to_index(x, y);
-            self.collect_index_if(&mut set, index, &how);
-        }
-        set
-    }
-
-    fn get_group_values(&self, x: Coordinate, y: Coordinate, exclude_self: bool) -> Vec<Placement> {
-        let mut set = Vec::new();
-        let group = &self.game.group_at(x, y);
-        let index_reference = self.xy_to_index(x, y);
-
-        for index in group.iter() {
-            if exclude_self && (index == index_reference) {
-                continue;
-            }
-
-            self.collect_if_set(&mut set, index);
+    /// Returns the peers of the cell as given by the constraints of the game, e.g. the cells
+    /// of its row, column and groups, along with the cell itself unless excluded.
+    fn peer_cells(&self, x: Coordinate, y: Coordinate, exclude_self: bool) -> IndexBitSet {
+        let index = self.xy_to_index(x, y);
+        let peers = self.game.peers(index);
+        match exclude_self {
+            true => peers,
+            false => peers.with_value(index),
         }
-        set
-    }
-
-    fn get_group_indexes(
-        &self,
-        x: Coordinate,
-        y: Coordinate,
-        exclude_self: bool,
-        how: &CollectType,
-    ) -> Vec<Index> {
-        let mut set = Vec::new();
-        let group = &self.game.group_at(x, y);
-        let index_reference = self.xy_to_index(x, y);
-
-        for index in group.iter() {
-            if exclude_self && (index == index_reference) {
-                continue;
-            }
-
-            self.collect_index_if(&mut set, index, &how);
-        }
-        set
     }
 
     fn cell_at_index(&self, index: Index) -> ValueOption {
@@ -255,56 +158,19 @@ impl GameState {
         (x, y)
     }
 
+    /// Determines whether the values satisfy all constraints of the game, i.e. its houses,
+    /// variant clues and custom constraints. Unless `allow_empty` is set, every cell must be
+    /// filled as well.
     pub fn validate(&self, allow_empty: bool) -> bool {
-        let mut valid = true;
-        for y in 0..self.game.height {
-            valid &= self.validate_row(y, allow_empty);
-        }
+        let values = self.state.values();
+        let mut valid = allow_empty || values.iter().all(|value| value.is_some());
 
-        for x in 0..self.game.width {
-            valid &= self.validate_column(x, allow_empty);
-        }
-
-        for group in self.game.groups.as_slice() {
-            valid &= self.validate_group(group, allow_empty);
+        for constraint in self.game.constraints() {
+            valid &= constraint.check(values);
         }
 
         valid
     }
-
-    fn validate_row(&self, y: Coordinate, allow_empty: bool) -> bool {
-        let mut values = ValueBitSet::default();
-        for item in self.get_row_values(0, y, false) {
-            if values.contains(item.value) {
-                return false;
-            }
-            values.insert(item.value);
-        }
-        values.len() == self.game.width || allow_empty
-    }
-
-    fn validate_column(&self, x: Coordinate, allow_empty: bool) -> bool {
-        let mut values = ValueBitSet::default();
-        for item in self.get_column_values(x, 0, false) {
-            if values.contains(item.value) {
-                return false;
-            }
-            values.insert(item.value);
-        }
-        values.len() == self.game.height || allow_empty
-    }
-
-    fn validate_group(&self, group: &IndexBitSet, allow_empty: bool) -> bool {
-        let mut values = ValueBitSet::default();
-        let (x, y) = self.index_to_xy(group.iter().next().unwrap());
-        for item in self.get_group_values(x, y, false) {
-            if values.contains(item.value) {
-                return false;
-            }
-            values.insert(item.value);
-        }
-        values.len() == group.len() || allow_empty
-    }
 }
 
 impl Clone for GameState {
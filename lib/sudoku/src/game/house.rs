@@ -0,0 +1,154 @@
+use crate::game::constraint::Constraint;
+use crate::game::prelude::*;
+use crate::solver::Technique;
+use crate::transform::{Transform, TransformError};
+use crate::Game;
+use std::fmt::{Display, Formatter};
+use std::rc::Rc;
+
+/// Identifies a house, i.e. a set of cells in which every value may appear at most once.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum HouseKind {
+    /// The row at the specified (zero-based) Y coordinate.
+    Row(usize),
+    /// The column at the specified (zero-based) X coordinate.
+    Column(usize),
+    /// The group at the specified (zero-based) index of [`Game::groups`](crate::Game::groups).
+    /// Additional houses, such as the windows of a Hypersudoku, follow the regions.
+    Group(usize),
+}
+
+/// A house of the game along with the cells it covers.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct House {
+    pub kind: HouseKind,
+    pub cells: IndexBitSet,
+}
+
+impl House {
+    pub fn new(kind: HouseKind, cells: IndexBitSet) -> House {
+        House { kind, cells }
+    }
+}
+
+/// A house holds every value at most once.
+impl Constraint for House {
+    fn cells(&self) -> IndexBitSet {
+        self.cells
+    }
+
+    fn check(&self, values: &[ValueOption]) -> bool {
+        let mut seen = ValueBitSet::default();
+        for value in self.cells.iter().filter_map(|index| values[index as usize]) {
+            if seen.contains(value) {
+                return false;
+            }
+            seen.insert(value);
+        }
+        true
+    }
+
+    /// Removes the values of cells with a single candidate from the rest of the house, and
+    /// places each value that fits into a single cell only.
+    fn prune(&self, candidates: &mut [ValueBitSet]) {
+        for index in self.cells.iter() {
+            let single = candidates[index as usize];
+            if single.len() != 1 {
+                continue;
+            }
+
+            for other in self.cells.iter().filter(|&other| other != index) {
+                let cell = &mut candidates[other as usize];
+                *cell = cell.without_all(&single);
+            }
+        }
+
+        let mut values = ValueBitSet::default();
+        for index in self.cells.iter() {
+            values.union(&candidates[index as usize]);
+        }
+        for value in values.iter() {
+            let mut positions = self
+                .cells
+                .iter()
+                .filter(|&index| candidates[index as usize].contains(value));
+            if let (Some(index), None) = (positions.next(), positions.next()) {
+                candidates[index as usize] = ValueBitSet::default().with_value(value);
+            }
+        }
+    }
+
+    fn technique(&self) -> Technique {
+        Technique::HiddenSingle
+    }
+
+    fn describe(&self, _game: &Game) -> String {
+        self.kind.to_string()
+    }
+
+    fn peers(&self, index: Index) -> IndexBitSet {
+        match self.cells.contains(index) {
+            true => self.cells.without_value(index),
+            false => IndexBitSet::default(),
+        }
+    }
+
+    /// Moves the cells of the house. A row or column may turn into the other, but must remain
+    /// a line of the board.
+    fn transform(&self, transform: &Transform) -> Result<Rc<dyn Constraint>, TransformError> {
+        let cells = transform.cells(&self.cells);
+        let kind = match self.kind {
+            HouseKind::Row(_) | HouseKind::Column(_) => {
+                let first = cells.iter().next().unwrap_or_default() as usize;
+                if cells.iter().all(|index| index as usize / 9 == first / 9) {
+                    HouseKind::Row(first / 9)
+                } else if cells.iter().all(|index| index as usize % 9 == first % 9) {
+                    HouseKind::Column(first % 9)
+                } else {
+                    return Err(TransformError::BrokenLine);
+                }
+            }
+            group => group,
+        };
+        Ok(Rc::new(House::new(kind, cells)))
+    }
+}
+
+impl Display for HouseKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HouseKind::Row(y) => write!(f, "row {}", y + 1),
+            HouseKind::Column(x) => write!(f, "column {}", x + 1),
+            HouseKind::Group(id) => write!(f, "group {}", id + 1),
+        }
+    }
+}
+
+impl Display for House {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.kind.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::constraint::testing::filled;
+
+    #[test]
+    fn houses_hold_distinct_values() {
+        let row = House::new(HouseKind::Row(0), IndexBitSet::from(&[0u8, 1, 2][..]));
+        let mut values = filled(&[(0, 4), (9, 4)]);
+        assert!(row.check(&values));
+
+        values[2] = Value::new(4);
+        assert!(!row.check(&values));
+
+        let mut candidates = [ValueBitSet::from(&[1u8, 2, 3][..]); 81];
+        candidates[0] = ValueBitSet::from(&[1u8][..]);
+        candidates[1] = ValueBitSet::from(&[1u8, 2][..]);
+        row.prune(&mut candidates);
+        assert_eq!(candidates[1], ValueBitSet::from(&[2u8][..]));
+        assert_eq!(candidates[2], ValueBitSet::from(&[3u8][..]));
+    }
+}
@@ -57,6 +57,29 @@ impl IndexBitSet {
         self
     }
 
+    #[inline]
+    pub const fn with_intersection(mut self, other: &IndexBitSet) -> Self {
+        self.state &= other.state;
+        self
+    }
+
+    #[inline]
+    pub fn intersect(&mut self, other: &IndexBitSet) -> &mut Self {
+        self.state &= other.state;
+        self
+    }
+
+    #[inline]
+    pub const fn without_all(mut self, other: &IndexBitSet) -> Self {
+        self.state &= !other.state;
+        self
+    }
+
+    #[inline]
+    pub const fn is_subset(&self, other: &IndexBitSet) -> bool {
+        self.state & !other.state == 0
+    }
+
     #[inline]
     pub const fn contains(&self, index: Index) -> bool {
         if index >= 81 {
@@ -158,6 +181,17 @@ mod tests {
         assert!(!bitset.contains(c));
     }
 
+    #[test]
+    fn intersection_and_difference() {
+        let a = IndexBitSet::from([2, 17, 80].as_slice());
+        let b = IndexBitSet::from([17, 40].as_slice());
+
+        assert_eq!(a.with_intersection(&b), IndexBitSet::from([17].as_slice()));
+        assert_eq!(a.without_all(&b), IndexBitSet::from([2, 80].as_slice()));
+        assert!(a.with_intersection(&b).is_subset(&a));
+        assert!(!b.is_subset(&a));
+    }
+
     #[test]
     fn without_value() {
         let a = 80;
@@ -0,0 +1,99 @@
+use crate::game::constraint::{retain, Constraint};
+use crate::game::prelude::*;
+use crate::solver::Technique;
+use crate::transform::{Transform, TransformError};
+use crate::Game;
+use std::rc::Rc;
+
+/// A `<` or `>` sign between two orthogonally adjacent cells, pointing at the smaller digit.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Inequality {
+    pub greater: Index,
+    pub less: Index,
+}
+
+impl Inequality {
+    /// Creates a sign requiring the digit of `greater` to exceed the digit of `less`.
+    ///
+    /// ## Panics
+    /// Panics if the cells are not orthogonally adjacent on a 9x9 board.
+    pub fn new(greater: Index, less: Index) -> Inequality {
+        assert!(
+            orthogonal_neighbors(greater, 9, 9).contains(&less),
+            "The cells of an inequality must be orthogonally adjacent"
+        );
+        Inequality { greater, less }
+    }
+}
+
+impl Constraint for Inequality {
+    fn cells(&self) -> IndexBitSet {
+        IndexBitSet::default()
+            .with_value(self.greater)
+            .with_value(self.less)
+    }
+
+    fn check(&self, values: &[ValueOption]) -> bool {
+        match (values[self.greater as usize], values[self.less as usize]) {
+            (Some(greater), Some(less)) => greater > less,
+            _ => true,
+        }
+    }
+
+    fn prune(&self, candidates: &mut [ValueBitSet]) {
+        let floor = candidates[self.less as usize]
+            .iter()
+            .map(|value| value.get())
+            .min()
+            .unwrap_or(9);
+        let greater = &mut candidates[self.greater as usize];
+        *greater = retain(greater, |digit| digit > floor);
+
+        let ceiling = candidates[self.greater as usize]
+            .iter()
+            .map(|value| value.get())
+            .max()
+            .unwrap_or(1);
+        let less = &mut candidates[self.less as usize];
+        *less = retain(less, |digit| digit < ceiling);
+    }
+
+    fn technique(&self) -> Technique {
+        Technique::GreaterThan
+    }
+
+    fn describe(&self, game: &Game) -> String {
+        format!(
+            "{} is greater than {}",
+            game.cell_name(self.greater),
+            game.cell_name(self.less)
+        )
+    }
+
+    fn transform(&self, transform: &Transform) -> Result<Rc<dyn Constraint>, TransformError> {
+        transform.keep_digits()?;
+        let (greater, less) = transform.adjacent((self.greater, self.less))?;
+        Ok(Rc::new(Inequality::new(greater, less)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::constraint::testing::{all_candidates, filled};
+
+    #[test]
+    fn orders_neighbors() {
+        let inequality = Inequality::new(1, 0);
+        let values = filled(&[(0, 5), (1, 3)]);
+        assert!(!inequality.check(&values));
+
+        let mut candidates = all_candidates();
+        candidates[0] = ValueBitSet::from(&[4u8, 7][..]);
+        candidates[1] = ValueBitSet::from(&[2u8, 5, 6][..]);
+        inequality.prune(&mut candidates);
+
+        assert_eq!(candidates[1], ValueBitSet::from(&[5u8, 6][..]));
+        assert_eq!(candidates[0], ValueBitSet::from(&[4u8][..]));
+    }
+}
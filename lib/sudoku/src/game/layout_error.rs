@@ -0,0 +1,60 @@
+use crate::game::prelude::*;
+use std::fmt::{Display, Formatter};
+
+/// Describes why a group layout was rejected by
+/// [`Game::try_new_with_groups`](crate::Game::try_new_with_groups).
+///
+/// Groups are numbered from zero in the order in which they were passed.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum LayoutError {
+    /// The group does not consist of exactly nine cells.
+    WrongCellCount { group: usize, cells: usize },
+    /// The group contains a cell outside of the board.
+    OutOfBounds { group: usize, index: usize },
+    /// The cell is not covered by any region.
+    UncoveredCell(Index),
+    /// The cell is covered by two regions.
+    Overlap {
+        index: Index,
+        groups: (usize, usize),
+    },
+    /// The region is not orthogonally connected.
+    DisconnectedRegion(usize),
+}
+
+impl Display for LayoutError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            LayoutError::WrongCellCount { group, cells } => {
+                write!(f, "group {} has {} cells instead of 9", group + 1, cells)
+            }
+            LayoutError::OutOfBounds { group, index } => {
+                write!(
+                    f,
+                    "group {} contains cell {} outside of the board",
+                    group + 1,
+                    index
+                )
+            }
+            LayoutError::UncoveredCell(index) => {
+                write!(
+                    f,
+                    "cell {} is not covered by any group",
+                    cell_name(*index, 9)
+                )
+            }
+            LayoutError::Overlap { index, groups } => write!(
+                f,
+                "groups {} and {} overlap in cell {}",
+                groups.0 + 1,
+                groups.1 + 1,
+                cell_name(*index, 9)
+            ),
+            LayoutError::DisconnectedRegion(group) => {
+                write!(f, "group {} is not connected", group + 1)
+            }
+        }
+    }
+}
+
+impl std::error::Error for LayoutError {}
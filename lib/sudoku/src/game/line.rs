@@ -0,0 +1,236 @@
+use crate::game::constraint::{retain, Constraint};
+use crate::game::edge::prune_pair;
+use crate::game::prelude::*;
+use crate::solver::Technique;
+use crate::transform::{Transform, TransformError};
+use crate::Game;
+use std::rc::Rc;
+
+/// The rule of a line drawn through the board.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum LineKind {
+    /// Adjacent digits along a German whispers line differ by at least 5.
+    GermanWhispers,
+    /// A renban line holds a set of consecutive digits in any order.
+    Renban,
+    /// A palindrome line reads the same in both directions.
+    Palindrome,
+}
+
+impl LineKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            LineKind::GermanWhispers => "German whispers line",
+            LineKind::Renban => "renban line",
+            LineKind::Palindrome => "palindrome line",
+        }
+    }
+}
+
+/// A line through a path of cells whose digits must satisfy its rule.
+///
+/// Consecutive cells of the path usually touch orthogonally or diagonally, but this is not
+/// required.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Line {
+    pub kind: LineKind,
+    /// The cells of the line in order from one end to the other.
+    pub cells: Vec<Index>,
+}
+
+impl Line {
+    /// Creates a line through the specified cells.
+    ///
+    /// ## Panics
+    /// Panics if the line has fewer than two cells, or if a renban line is longer than nine
+    /// cells, as its digits could not be distinct.
+    pub fn new(kind: LineKind, cells: Vec<Index>) -> Line {
+        assert!(cells.len() >= 2, "A line needs at least two cells");
+        assert!(
+            kind != LineKind::Renban || cells.len() <= 9,
+            "A renban line can hold at most nine cells"
+        );
+        Line { kind, cells }
+    }
+
+    /// Returns the pairs of cells along the line that are constrained against each other.
+    fn pairs(&self) -> Vec<(Index, Index)> {
+        match self.kind {
+            LineKind::GermanWhispers => self.cells.windows(2).map(|w| (w[0], w[1])).collect(),
+            LineKind::Renban => Vec::new(),
+            LineKind::Palindrome => {
+                let half = self.cells.len() / 2;
+                let mirrored = self.cells.iter().rev();
+                self.cells[..half]
+                    .iter()
+                    .copied()
+                    .zip(mirrored.copied())
+                    .collect()
+            }
+        }
+    }
+
+    fn allows(&self, a: u8, b: u8) -> bool {
+        match self.kind {
+            LineKind::GermanWhispers => a.abs_diff(b) >= 5,
+            LineKind::Renban => a != b,
+            LineKind::Palindrome => a == b,
+        }
+    }
+}
+
+impl Constraint for Line {
+    fn cells(&self) -> IndexBitSet {
+        let mut cells = IndexBitSet::default();
+        for &index in self.cells.iter() {
+            cells.insert(index);
+        }
+        cells
+    }
+
+    fn check(&self, values: &[ValueOption]) -> bool {
+        let digit = |index: Index| values[index as usize].map(|value| value.get());
+        let pairs_hold = self
+            .pairs()
+            .iter()
+            .all(|&(a, b)| match (digit(a), digit(b)) {
+                (Some(a), Some(b)) => self.allows(a, b),
+                _ => true,
+            });
+        if self.kind != LineKind::Renban {
+            return pairs_hold;
+        }
+
+        // The digits of a renban line are distinct and span no more than its length.
+        let digits: Vec<u8> = self
+            .cells
+            .iter()
+            .filter_map(|&index| digit(index))
+            .collect();
+        let distinct = ValueBitSet::from(digits.as_slice()).len() == digits.len();
+        let span = match (digits.iter().min(), digits.iter().max()) {
+            (Some(min), Some(max)) => (max - min) as usize,
+            _ => 0,
+        };
+        distinct && span < self.cells.len()
+    }
+
+    fn prune(&self, candidates: &mut [ValueBitSet]) {
+        for pair in self.pairs() {
+            prune_pair(pair, candidates, |a, b| self.allows(a, b));
+        }
+        if self.kind == LineKind::Renban {
+            prune_renban(&self.cells, candidates);
+        }
+    }
+
+    fn technique(&self) -> Technique {
+        match self.kind {
+            LineKind::GermanWhispers => Technique::GermanWhispers,
+            LineKind::Renban => Technique::Renban,
+            LineKind::Palindrome => Technique::Palindrome,
+        }
+    }
+
+    fn describe(&self, game: &Game) -> String {
+        let cells: Vec<_> = self
+            .cells
+            .iter()
+            .map(|&index| game.cell_name(index))
+            .collect();
+        format!("the {} {}", self.kind.name(), cells.join(", "))
+    }
+
+    fn transform(&self, transform: &Transform) -> Result<Rc<dyn Constraint>, TransformError> {
+        transform.keep_digits()?;
+        let cells = self.cells.iter().map(|&index| transform.target(index));
+        Ok(Rc::new(Line::new(self.kind, cells.collect())))
+    }
+}
+
+/// Restricts the cells of a renban line to the runs of consecutive digits the line can hold,
+/// and removes the digits of cells with a single candidate from the rest of the line.
+fn prune_renban(cells: &[Index], candidates: &mut [ValueBitSet]) {
+    let length = cells.len() as u8;
+    let mut allowed = vec![ValueBitSet::default(); cells.len()];
+    for start in 1..=10 - length {
+        let run = start..start + length;
+        let restricted: Vec<ValueBitSet> = cells
+            .iter()
+            .map(|&index| retain(&candidates[index as usize], |digit| run.contains(&digit)))
+            .collect();
+
+        // Every cell needs a digit of the run, and every digit of the run needs a cell.
+        let mut covered = ValueBitSet::default();
+        for cell in restricted.iter() {
+            covered.union(cell);
+        }
+        if restricted.iter().any(|cell| cell.is_empty()) || covered.len() < cells.len() {
+            continue;
+        }
+
+        for (allowed, cell) in allowed.iter_mut().zip(restricted.iter()) {
+            allowed.union(cell);
+        }
+    }
+
+    for (&index, allowed) in cells.iter().zip(allowed.iter()) {
+        let cell = &mut candidates[index as usize];
+        *cell = retain(cell, |digit| allowed.contains(Value::new(digit).unwrap()));
+    }
+
+    for &index in cells.iter() {
+        if candidates[index as usize].len() != 1 {
+            continue;
+        }
+
+        let single = candidates[index as usize];
+        for &other in cells.iter().filter(|&&other| other != index) {
+            let cell = &mut candidates[other as usize];
+            *cell = retain(cell, |digit| !single.contains(Value::new(digit).unwrap()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::constraint::testing::{all_candidates, filled};
+
+    #[test]
+    fn checks_line_rules() {
+        let whispers = Line::new(LineKind::GermanWhispers, vec![0, 10, 2]);
+        let renban = Line::new(LineKind::Renban, vec![0, 10, 2]);
+        let palindrome = Line::new(LineKind::Palindrome, vec![0, 10, 2]);
+        let mut values = filled(&[(0, 2), (10, 8)]);
+        assert!(whispers.check(&values));
+        assert!(!renban.check(&values));
+
+        values[10] = Value::new(3);
+        assert!(!whispers.check(&values));
+        assert!(renban.check(&values));
+        assert!(palindrome.check(&values));
+
+        values[2] = Value::new(5);
+        assert!(!renban.check(&values));
+        assert!(!palindrome.check(&values));
+    }
+
+    #[test]
+    fn prunes_line_candidates() {
+        let mut candidates = all_candidates();
+        Line::new(LineKind::GermanWhispers, vec![0, 1]).prune(&mut candidates);
+        assert_eq!(
+            candidates[0],
+            ValueBitSet::from(&[1u8, 2, 3, 4, 6, 7, 8, 9][..])
+        );
+
+        candidates[9] = ValueBitSet::from(&[8u8][..]);
+        Line::new(LineKind::Renban, vec![9, 10, 11]).prune(&mut candidates);
+        assert_eq!(candidates[10], ValueBitSet::from(&[6u8, 7, 9][..]));
+
+        candidates[18] = ValueBitSet::from(&[3u8, 4][..]);
+        Line::new(LineKind::Palindrome, vec![18, 19, 20]).prune(&mut candidates);
+        assert_eq!(candidates[20], ValueBitSet::from(&[3u8, 4][..]));
+    }
+}
@@ -0,0 +1,231 @@
+use crate::game::constraint::{retain, Constraint};
+use crate::game::prelude::*;
+use crate::solver::Technique;
+use crate::transform::{Transform, TransformError};
+use crate::Game;
+use std::rc::Rc;
+
+/// The direction in which a little killer clue points along its diagonal.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Diagonal {
+    DownRight,
+    DownLeft,
+    UpRight,
+    UpLeft,
+}
+
+impl Diagonal {
+    /// Returns the diagonal that steps by the specified (x, y) offsets, if any.
+    fn from_step(dx: isize, dy: isize) -> Option<Diagonal> {
+        match (dx, dy) {
+            (1, 1) => Some(Diagonal::DownRight),
+            (-1, 1) => Some(Diagonal::DownLeft),
+            (1, -1) => Some(Diagonal::UpRight),
+            (-1, -1) => Some(Diagonal::UpLeft),
+            _ => None,
+        }
+    }
+
+    /// Returns the step along the diagonal as (x, y) offsets.
+    pub(crate) fn step(&self) -> (isize, isize) {
+        match self {
+            Diagonal::DownRight => (1, 1),
+            Diagonal::DownLeft => (-1, 1),
+            Diagonal::UpRight => (1, -1),
+            Diagonal::UpLeft => (-1, -1),
+        }
+    }
+}
+
+/// A little killer clue outside of the board: the digits along the diagonal it points at
+/// add up to the sum. Unlike in a killer cage, digits may repeat.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct LittleKiller {
+    /// The first cell of the diagonal, next to the clue.
+    pub start: Index,
+    pub direction: Diagonal,
+    pub sum: u8,
+}
+
+impl LittleKiller {
+    /// Creates a clue pointing from outside of the board at the specified cell and along
+    /// the diagonal beyond it.
+    ///
+    /// ## Panics
+    /// Panics if the cell before the start is on the board, i.e. if the clue would not sit
+    /// outside of it, or if the sum exceeds 81, the largest sum of nine digits.
+    pub fn new(start: Index, direction: Diagonal, sum: u8) -> LittleKiller {
+        let (dx, dy) = direction.step();
+        assert!(
+            neighbor(start, -dx, -dy).is_none(),
+            "A little killer clue must sit outside of the board"
+        );
+        assert!(sum <= 81, "A diagonal cannot sum to {}", sum);
+        LittleKiller {
+            start,
+            direction,
+            sum,
+        }
+    }
+
+    /// Returns the cells of the diagonal, starting next to the clue.
+    pub fn cells(&self) -> Vec<Index> {
+        let (dx, dy) = self.direction.step();
+        let mut cells = vec![self.start];
+        while let Some(next) = neighbor(*cells.last().unwrap(), dx, dy) {
+            cells.push(next);
+        }
+        cells
+    }
+}
+
+impl Constraint for LittleKiller {
+    fn cells(&self) -> IndexBitSet {
+        let mut cells = IndexBitSet::default();
+        for index in LittleKiller::cells(self) {
+            cells.insert(index);
+        }
+        cells
+    }
+
+    fn check(&self, values: &[ValueOption]) -> bool {
+        let mut sum = 0;
+        let mut open = 0;
+        for index in LittleKiller::cells(self) {
+            match values[index as usize] {
+                Some(value) => sum += value.get() as usize,
+                None => open += 1,
+            }
+        }
+
+        // Every open cell adds at least 1 and at most 9, repeats being allowed.
+        (sum + open..=sum + 9 * open).contains(&(self.sum as usize))
+    }
+
+    fn prune(&self, candidates: &mut [ValueBitSet]) {
+        let cells = LittleKiller::cells(self);
+
+        // The possible sums of the cells before and after each cell, as bit masks.
+        let mut before = vec![1u128; cells.len()];
+        for i in 1..cells.len() {
+            before[i] = add(before[i - 1], &candidates[cells[i - 1] as usize]);
+        }
+        let mut after = vec![1u128; cells.len()];
+        for i in (0..cells.len() - 1).rev() {
+            after[i] = add(after[i + 1], &candidates[cells[i + 1] as usize]);
+        }
+
+        for (i, &index) in cells.iter().enumerate() {
+            let rest = combine(before[i], after[i]);
+            let cell = &mut candidates[index as usize];
+            *cell = retain(cell, |digit| {
+                (digit <= self.sum) && rest & (1 << (self.sum - digit)) != 0
+            });
+        }
+    }
+
+    fn technique(&self) -> Technique {
+        Technique::LittleKiller
+    }
+
+    fn describe(&self, game: &Game) -> String {
+        let cells: Vec<_> = LittleKiller::cells(self)
+            .iter()
+            .map(|&index| game.cell_name(index))
+            .collect();
+        format!("the diagonal {} sums to {}", cells.join(", "), self.sum)
+    }
+
+    /// Moves the clue along with its diagonal. Fails if the cells no longer form a diagonal
+    /// running from the edge of the board.
+    fn transform(&self, transform: &Transform) -> Result<Rc<dyn Constraint>, TransformError> {
+        transform.keep_digits()?;
+        let cells: Vec<Index> = LittleKiller::cells(self)
+            .iter()
+            .map(|&index| transform.target(index))
+            .collect();
+        let start = cells[0];
+        let (x, y) = (start as isize % 9, start as isize / 9);
+        let (dx, dy) = match cells.get(1) {
+            Some(&next) => (next as isize % 9 - x, next as isize / 9 - y),
+            // A corner cell on its own is a diagonal in both directions away from the board.
+            None => (if x == 0 { -1 } else { 1 }, if y == 0 { 1 } else { -1 }),
+        };
+        let direction = Diagonal::from_step(dx, dy).ok_or(TransformError::BrokenLine)?;
+
+        // The clue must still sit outside of the board, and its diagonal run on to the edge.
+        if neighbor(start, -dx, -dy).is_some() {
+            return Err(TransformError::BrokenLine);
+        }
+        let transformed = LittleKiller::new(start, direction, self.sum);
+        match LittleKiller::cells(&transformed) == cells {
+            true => Ok(Rc::new(transformed)),
+            false => Err(TransformError::BrokenLine),
+        }
+    }
+}
+
+/// Returns the cell at the specified offset, if it is on the board.
+fn neighbor(cell: Index, dx: isize, dy: isize) -> Option<Index> {
+    let (x, y) = (cell as usize % 9, cell as usize / 9);
+    let x = x.checked_add_signed(dx).filter(|&x| x < 9)?;
+    let y = y.checked_add_signed(dy).filter(|&y| y < 9)?;
+    Some(index(x, y, 9) as Index)
+}
+
+/// Adds each candidate to each of the sums.
+fn add(sums: u128, candidates: &ValueBitSet) -> u128 {
+    candidates
+        .iter()
+        .fold(0, |result, value| result | sums << value.get())
+}
+
+/// Returns all sums of one sum from each of the sets.
+fn combine(first: u128, second: u128) -> u128 {
+    (0..u128::BITS)
+        .filter(|bit| first & (1 << bit) != 0)
+        .fold(0, |result, bit| result | second << bit)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::constraint::testing::{all_candidates, filled};
+
+    #[test]
+    fn walks_the_diagonal() {
+        let clue = LittleKiller::new(6, Diagonal::DownRight, 10);
+        assert_eq!(LittleKiller::cells(&clue), vec![6, 16, 26]);
+
+        let clue = LittleKiller::new(72, Diagonal::UpRight, 45);
+        assert_eq!(LittleKiller::cells(&clue).len(), 9);
+    }
+
+    #[test]
+    #[should_panic(expected = "outside of the board")]
+    fn rejects_clues_inside_the_board() {
+        LittleKiller::new(40, Diagonal::DownLeft, 10);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot sum to 200")]
+    fn rejects_sums_beyond_nine_digits() {
+        LittleKiller::new(72, Diagonal::UpRight, 200);
+    }
+
+    #[test]
+    fn prunes_from_feasible_sums() {
+        // After the 3, the other two cells add up to 6, e.g. as 3 + 3 since digits may repeat.
+        let clue = LittleKiller::new(6, Diagonal::DownRight, 9);
+        let mut candidates = all_candidates();
+        candidates[6] = ValueBitSet::from(&[3u8][..]);
+        clue.prune(&mut candidates);
+        assert_eq!(candidates[16], ValueBitSet::from(&[1u8, 2, 3, 4, 5][..]));
+
+        let mut values = filled(&[(6, 3), (16, 3)]);
+        assert!(clue.check(&values));
+
+        values[26] = Value::new(4);
+        assert!(!clue.check(&values));
+    }
+}
@@ -0,0 +1,112 @@
+use crate::game::constraint::Constraint;
+use crate::game::edge::prune_pair;
+use crate::game::prelude::*;
+use crate::solver::Technique;
+use crate::transform::{Transform, TransformError};
+use crate::{Game, Placement};
+use std::rc::Rc;
+
+/// The non-consecutive rule: orthogonally adjacent cells must not hold consecutive digits.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct NonConsecutive;
+
+impl NonConsecutive {
+    /// Returns the pairs of orthogonally adjacent cells, the lower index first.
+    fn pairs(&self) -> impl Iterator<Item = (Index, Index)> {
+        (0..81).flat_map(move |index| {
+            orthogonal_neighbors(index, 9, 9)
+                .into_iter()
+                .filter(move |&neighbor| index < neighbor)
+                .map(move |neighbor| (index, neighbor))
+        })
+    }
+}
+
+impl Constraint for NonConsecutive {
+    fn cells(&self) -> IndexBitSet {
+        let mut cells = IndexBitSet::default();
+        for index in 0..81 {
+            cells.insert(index);
+        }
+        cells
+    }
+
+    fn check(&self, values: &[ValueOption]) -> bool {
+        self.pairs()
+            .all(|(a, b)| match (values[a as usize], values[b as usize]) {
+                (Some(a), Some(b)) => a.get().abs_diff(b.get()) != 1,
+                _ => true,
+            })
+    }
+
+    fn prune(&self, candidates: &mut [ValueBitSet]) {
+        for pair in self.pairs() {
+            prune_pair(pair, candidates, |a, b| a.abs_diff(b) != 1);
+        }
+    }
+
+    fn technique(&self) -> Technique {
+        Technique::NonConsecutive
+    }
+
+    fn describe(&self, _game: &Game) -> String {
+        "adjacent cells cannot hold consecutive digits".to_string()
+    }
+
+    fn transform(&self, transform: &Transform) -> Result<Rc<dyn Constraint>, TransformError> {
+        transform.keep_digits()?;
+        transform.keep_neighborhoods()?;
+        Ok(Rc::new(NonConsecutive))
+    }
+}
+
+/// Returns the candidates that a placement rules out in the orthogonally adjacent cells,
+/// if the game is non-consecutive.
+pub(crate) fn consecutive_neighbors(game: &Game, placement: &Placement) -> Vec<Placement> {
+    if game.constraints_of::<NonConsecutive>().next().is_none() {
+        return Vec::new();
+    }
+
+    let value = placement.value.get();
+    let mut excluded = Vec::new();
+    for neighbor in orthogonal_neighbors(placement.index, game.width, game.height) {
+        for digit in [value - 1, value + 1] {
+            if let Some(&digit) = game.valid_symbols().iter().find(|d| d.get() == digit) {
+                excluded.push(Placement::new(digit, neighbor));
+            }
+        }
+    }
+    excluded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::constraint::testing::{all_candidates, filled};
+
+    #[test]
+    fn forbids_consecutive_neighbors() {
+        let rule = NonConsecutive;
+        let mut values = filled(&[(0, 5), (10, 6)]);
+        assert!(rule.check(&values));
+
+        values[1] = Value::new(4);
+        assert!(!rule.check(&values));
+
+        let mut candidates = all_candidates();
+        candidates[0] = ValueBitSet::from(&[4u8, 6][..]);
+        rule.prune(&mut candidates);
+        assert!(!candidates[1].contains(Value::new(5).unwrap()));
+        assert!(candidates[1].contains(Value::new(4).unwrap()));
+    }
+
+    #[test]
+    fn placements_exclude_neighboring_digits() {
+        let placement = Placement::new(Value::new(9).unwrap(), 0);
+        let excluded = consecutive_neighbors(&Game::new_example_non_consecutive(), &placement);
+        assert_eq!(excluded.len(), 2);
+        assert!(excluded.iter().all(|placement| placement.value.get() == 8));
+
+        assert!(consecutive_neighbors(&Game::new_empty(), &placement).is_empty());
+    }
+}
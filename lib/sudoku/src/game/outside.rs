@@ -0,0 +1,388 @@
+use crate::game::constraint::{retain, Constraint};
+use crate::game::prelude::*;
+use crate::game::skyscraper::prune_visibility;
+use crate::solver::Technique;
+use crate::transform::{Transform, TransformError};
+use crate::Game;
+use std::rc::Rc;
+
+/// The side of the board an outside clue is written on. The row or column of the clue is
+/// read starting from that side.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Side {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+/// The rule of an outside clue.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum OutsideKind {
+    /// The digits between the 1 and the 9 add up to the clue.
+    Sandwich,
+    /// The first X digits add up to the clue, where X is the first digit itself.
+    XSum,
+    /// The clue counts the digits visible from its side, taller digits hiding the shorter
+    /// ones behind them like skyscrapers.
+    Skyscraper,
+}
+
+/// A sum or count written outside of the board next to a row or column.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct OutsideClue {
+    pub kind: OutsideKind,
+    pub side: Side,
+    /// The row (for clues on the left or right) or column (for clues above or below) of the clue.
+    pub line: usize,
+    /// The sum given by the clue, or the number of visible digits for a skyscraper clue.
+    pub sum: u8,
+}
+
+impl OutsideClue {
+    /// Creates a clue next to the specified row or column.
+    ///
+    /// ## Panics
+    /// Panics if the line lies outside of a 9x9 board, or if no line can satisfy the clue:
+    /// a sandwich above 35 (the digits 2 to 8), an X-sum of 0 or above 45 (all nine digits),
+    /// or a skyscraper count of 0 or above 9.
+    pub fn new(kind: OutsideKind, side: Side, line: usize, sum: u8) -> OutsideClue {
+        assert!(line < 9, "The line of a clue must lie on the board");
+        let range = match kind {
+            OutsideKind::Sandwich => 0..=35,
+            OutsideKind::XSum => 1..=45,
+            OutsideKind::Skyscraper => 1..=9,
+        };
+        assert!(range.contains(&sum), "A {:?} clue cannot be {}", kind, sum);
+        OutsideClue {
+            kind,
+            side,
+            line,
+            sum,
+        }
+    }
+
+    /// Returns the cells of the row or column in reading order, starting next to the clue.
+    pub fn cells(&self) -> [Index; 9] {
+        std::array::from_fn(|i| {
+            let (x, y) = match self.side {
+                Side::Top => (self.line, i),
+                Side::Bottom => (self.line, 8 - i),
+                Side::Left => (i, self.line),
+                Side::Right => (8 - i, self.line),
+            };
+            index(x, y, 9) as Index
+        })
+    }
+
+    fn location(&self) -> String {
+        match self.side {
+            Side::Top => format!("above column {}", self.line + 1),
+            Side::Bottom => format!("below column {}", self.line + 1),
+            Side::Left => format!("left of row {}", self.line + 1),
+            Side::Right => format!("right of row {}", self.line + 1),
+        }
+    }
+}
+
+impl Constraint for OutsideClue {
+    fn cells(&self) -> IndexBitSet {
+        let mut cells = IndexBitSet::default();
+        for index in OutsideClue::cells(self) {
+            cells.insert(index);
+        }
+        cells
+    }
+
+    fn check(&self, values: &[ValueOption]) -> bool {
+        let line: Vec<ValueOption> = OutsideClue::cells(self)
+            .iter()
+            .map(|&index| values[index as usize])
+            .collect();
+
+        let window = match self.kind {
+            OutsideKind::Sandwich => {
+                let position = |digit| line.iter().position(|v| v.map(|v| v.get()) == Some(digit));
+                let (Some(a), Some(b)) = (position(1), position(9)) else {
+                    return true;
+                };
+                &line[a.min(b) + 1..a.max(b)]
+            }
+            OutsideKind::XSum => match line[0] {
+                Some(first) => &line[..first.get() as usize],
+                None => return true,
+            },
+            OutsideKind::Skyscraper => {
+                // The open cells may take any digit not placed in the line yet.
+                let placed: Vec<u8> = line.iter().flatten().map(|value| value.get()).collect();
+                let placed = ValueBitSet::from(placed.as_slice());
+                let candidates: Vec<ValueBitSet> = line
+                    .iter()
+                    .map(|value| match value {
+                        Some(value) => ValueBitSet::default().with_value(*value),
+                        None => ValueBitSet::from(&[1u8, 2, 3, 4, 5, 6, 7, 8, 9][..])
+                            .without_all(&placed),
+                    })
+                    .collect();
+                return !prune_visibility(&candidates, self.sum)[0].is_empty();
+            }
+        };
+
+        // Every open cell of the window adds at least 1 and at most 9.
+        let sum: u8 = window.iter().flatten().map(|value| value.get()).sum();
+        let open = window.iter().filter(|value| value.is_none()).count() as u8;
+        (sum + open..=sum + 9 * open).contains(&self.sum)
+    }
+
+    fn prune(&self, candidates: &mut [ValueBitSet]) {
+        let cells = OutsideClue::cells(self);
+        let line: Vec<ValueBitSet> = cells
+            .iter()
+            .map(|&index| candidates[index as usize])
+            .collect();
+
+        let allowed = match self.kind {
+            OutsideKind::Sandwich => prune_sandwich(&line, self.sum),
+            OutsideKind::XSum => prune_x_sum(&line, self.sum),
+            OutsideKind::Skyscraper => prune_visibility(&line, self.sum),
+        };
+        for (&index, allowed) in cells.iter().zip(allowed.iter()) {
+            let cell = &mut candidates[index as usize];
+            *cell = retain(cell, |digit| allowed.contains(Value::new(digit).unwrap()));
+        }
+    }
+
+    fn technique(&self) -> Technique {
+        match self.kind {
+            OutsideKind::Sandwich => Technique::Sandwich,
+            OutsideKind::XSum => Technique::XSum,
+            OutsideKind::Skyscraper => Technique::Skyscraper,
+        }
+    }
+
+    fn describe(&self, _game: &Game) -> String {
+        match self.kind {
+            OutsideKind::Sandwich => format!(
+                "the sandwich clue {} {} sums the digits between the 1 and the 9",
+                self.sum,
+                self.location()
+            ),
+            OutsideKind::XSum => format!(
+                "the X-sum clue {} {} sums the first X digits",
+                self.sum,
+                self.location()
+            ),
+            OutsideKind::Skyscraper => format!(
+                "the skyscraper clue {} {} counts the visible digits",
+                self.sum,
+                self.location()
+            ),
+        }
+    }
+
+    /// Moves the clue to the side of the board its row or column now starts from. Fails if the
+    /// cells of the row or column are no longer read in order.
+    fn transform(&self, transform: &Transform) -> Result<Rc<dyn Constraint>, TransformError> {
+        transform.keep_digits()?;
+        let cells = OutsideClue::cells(self).map(|index| transform.target(index));
+        let (first, last) = (cells[0] as usize, cells[8] as usize);
+        let (side, line) = match (first % 9 == last % 9, first < last) {
+            (true, true) => (Side::Top, first % 9),
+            (true, false) => (Side::Bottom, first % 9),
+            (false, true) => (Side::Left, first / 9),
+            (false, false) => (Side::Right, first / 9),
+        };
+
+        let transformed = OutsideClue::new(self.kind, side, line, self.sum);
+        match OutsideClue::cells(&transformed) == cells {
+            true => Ok(Rc::new(transformed)),
+            false => Err(TransformError::BrokenLine),
+        }
+    }
+}
+
+/// Returns the candidates of the line that fit any placement of the 1 and the 9 whose
+/// cells in between can add up to the sum.
+fn prune_sandwich(line: &[ValueBitSet], sum: u8) -> Vec<ValueBitSet> {
+    let (one, nine) = (Value::new(1).unwrap(), Value::new(9).unwrap());
+    let mut allowed = vec![ValueBitSet::default(); line.len()];
+    for a in (0..line.len()).filter(|&a| line[a].contains(one)) {
+        for b in (0..line.len()).filter(|&b| b != a && line[b].contains(nine)) {
+            let (low, high) = (a.min(b), a.max(b));
+            let inner: Vec<ValueBitSet> = line[low + 1..high]
+                .iter()
+                .map(|cell| retain(cell, |digit| digit != 1 && digit != 9))
+                .collect();
+            let Some(inner) = window_candidates(&inner, sum) else {
+                continue;
+            };
+
+            allowed[a].insert(one);
+            allowed[b].insert(nine);
+            for (i, cell) in allowed.iter_mut().enumerate() {
+                if i > low && i < high {
+                    cell.union(&inner[i - low - 1]);
+                } else if i != a && i != b {
+                    cell.union(&retain(&line[i], |digit| digit != 1 && digit != 9));
+                }
+            }
+        }
+    }
+    allowed
+}
+
+/// Returns the candidates of the line that fit any first digit whose window can add up
+/// to the sum.
+fn prune_x_sum(line: &[ValueBitSet], sum: u8) -> Vec<ValueBitSet> {
+    let mut allowed = vec![ValueBitSet::default(); line.len()];
+    for first in line[0].iter() {
+        let count = first.get() as usize;
+        let mut window = vec![ValueBitSet::default().with_value(first)];
+        window.extend(
+            line[1..count]
+                .iter()
+                .map(|cell| retain(cell, |digit| digit != first.get())),
+        );
+        let Some(window) = window_candidates(&window, sum) else {
+            continue;
+        };
+
+        for (i, cell) in allowed.iter_mut().enumerate() {
+            match window.get(i) {
+                Some(candidates) => cell.union(candidates),
+                None => cell.union(&retain(&line[i], |digit| digit != first.get())),
+            };
+        }
+    }
+    allowed
+}
+
+/// Returns the candidates of each cell of a window of distinct digits that can add up to
+/// the sum, or `None` if the window cannot reach the sum at all.
+fn window_candidates(window: &[ValueBitSet], sum: u8) -> Option<Vec<ValueBitSet>> {
+    let bounds: Vec<(u8, u8)> = window
+        .iter()
+        .map(|cell| {
+            let digits: Vec<u8> = cell.iter().map(|value| value.get()).collect();
+            let low = digits.iter().copied().min().unwrap_or(10);
+            (low, digits.iter().copied().max().unwrap_or(0))
+        })
+        .collect();
+    if bounds.iter().any(|&(low, high)| low > high) {
+        return None;
+    }
+
+    // The digits are distinct, so they add up to at least the smallest and at most the
+    // largest digits of all cells taken together.
+    let mut union = ValueBitSet::default();
+    for cell in window.iter() {
+        union.union(cell);
+    }
+    let digits: Vec<u8> = union.iter().map(|value| value.get()).collect();
+    if digits.len() < window.len() {
+        return None;
+    }
+    let smallest: u8 = digits[..window.len()].iter().sum();
+    let largest: u8 = digits[digits.len() - window.len()..].iter().sum();
+
+    let min: u8 = bounds.iter().map(|&(low, _)| low).sum();
+    let max: u8 = bounds.iter().map(|&(_, high)| high).sum();
+    if !(min.max(smallest)..=max.min(largest)).contains(&sum) {
+        return None;
+    }
+
+    // Each cell makes up the difference to the sum, given the bounds of the other cells.
+    let cells = window
+        .iter()
+        .zip(bounds.iter())
+        .map(|(cell, &(low, high))| {
+            let (rest_min, rest_max) = (min - low, max - high);
+            retain(cell, |digit| {
+                digit + rest_min <= sum && digit + rest_max >= sum
+            })
+        })
+        .collect();
+    Some(cells)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::constraint::testing::{all_candidates, filled};
+
+    #[test]
+    fn reads_lines_from_the_clue() {
+        let clue = OutsideClue::new(OutsideKind::XSum, Side::Bottom, 2, 10);
+        assert_eq!(OutsideClue::cells(&clue)[..3], [74, 65, 56]);
+
+        let clue = OutsideClue::new(OutsideKind::XSum, Side::Right, 1, 10);
+        assert_eq!(OutsideClue::cells(&clue)[..3], [17, 16, 15]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Sandwich clue cannot be 36")]
+    fn rejects_sandwiches_beyond_the_digits_between() {
+        OutsideClue::new(OutsideKind::Sandwich, Side::Top, 0, 36);
+    }
+
+    #[test]
+    #[should_panic(expected = "XSum clue cannot be 46")]
+    fn rejects_x_sums_beyond_nine_digits() {
+        OutsideClue::new(OutsideKind::XSum, Side::Top, 0, 46);
+    }
+
+    #[test]
+    #[should_panic(expected = "Skyscraper clue cannot be 0")]
+    fn rejects_skyscraper_counts_off_the_line() {
+        OutsideClue::new(OutsideKind::Skyscraper, Side::Top, 0, 0);
+    }
+
+    #[test]
+    fn checks_sandwich_and_x_sums() {
+        let sandwich = OutsideClue::new(OutsideKind::Sandwich, Side::Left, 0, 5);
+        let mut values = filled(&[(1, 1), (4, 9)]);
+        assert!(sandwich.check(&values));
+
+        values[2] = Value::new(5);
+        assert!(!sandwich.check(&values));
+
+        values[2] = Value::new(3);
+        values[3] = Value::new(2);
+        assert!(sandwich.check(&values));
+
+        let x_sum = OutsideClue::new(OutsideKind::XSum, Side::Left, 0, 10);
+        values[0] = Value::new(3);
+        assert!(!x_sum.check(&values));
+
+        values[0] = Value::new(4);
+        assert!(x_sum.check(&values));
+
+        values[0] = Value::new(6);
+        assert!(!x_sum.check(&values));
+    }
+
+    #[test]
+    fn prunes_sandwich_positions() {
+        // A sandwich of 0 needs the 1 and the 9 next to each other.
+        let clue = OutsideClue::new(OutsideKind::Sandwich, Side::Top, 0, 0);
+        let mut candidates = [ValueBitSet::from(&[2u8, 3, 4, 5, 6, 7, 8, 9][..]); 81];
+        candidates[0] = ValueBitSet::from(&[1u8][..]);
+        clue.prune(&mut candidates);
+
+        assert_eq!(candidates[9], ValueBitSet::from(&[9u8][..]));
+        assert!(!candidates[18].contains(Value::new(9).unwrap()));
+    }
+
+    #[test]
+    fn prunes_x_sum_windows() {
+        // Only a 2 followed by a 5 adds up to 7: three or more digits exceed it.
+        let clue = OutsideClue::new(OutsideKind::XSum, Side::Left, 0, 7);
+        let mut candidates = all_candidates();
+        candidates[1] = ValueBitSet::from(&[4u8, 5][..]);
+        clue.prune(&mut candidates);
+
+        assert_eq!(candidates[0], ValueBitSet::from(&[2u8][..]));
+        assert_eq!(candidates[1], ValueBitSet::from(&[5u8][..]));
+        assert!(!candidates[2].contains(Value::new(2).unwrap()));
+    }
+}
@@ -0,0 +1,96 @@
+use crate::game::constraint::{retain, Constraint};
+use crate::game::prelude::*;
+use crate::solver::Technique;
+use crate::transform::{Transform, TransformError};
+use crate::Game;
+use std::rc::Rc;
+
+/// Whether a digit is odd or even.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Parity {
+    Odd,
+    Even,
+}
+
+impl Parity {
+    /// Determines whether the digit has this parity.
+    pub fn matches(&self, digit: u8) -> bool {
+        match self {
+            Parity::Odd => !digit.is_multiple_of(2),
+            Parity::Even => digit.is_multiple_of(2),
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Parity::Odd => "odd",
+            Parity::Even => "even",
+        }
+    }
+}
+
+/// A shaded cell that only takes odd or only even digits.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct ParityCell {
+    pub index: Index,
+    pub parity: Parity,
+}
+
+impl ParityCell {
+    pub fn new(index: Index, parity: Parity) -> ParityCell {
+        ParityCell { index, parity }
+    }
+}
+
+impl Constraint for ParityCell {
+    fn cells(&self) -> IndexBitSet {
+        IndexBitSet::default().with_value(self.index)
+    }
+
+    fn check(&self, values: &[ValueOption]) -> bool {
+        values[self.index as usize].is_none_or(|value| self.parity.matches(value.get()))
+    }
+
+    fn prune(&self, candidates: &mut [ValueBitSet]) {
+        let cell = &mut candidates[self.index as usize];
+        *cell = retain(cell, |digit| self.parity.matches(digit));
+    }
+
+    fn technique(&self) -> Technique {
+        Technique::OddEven
+    }
+
+    fn describe(&self, game: &Game) -> String {
+        format!(
+            "{} is shaded {}",
+            game.cell_name(self.index),
+            self.parity.name()
+        )
+    }
+
+    fn transform(&self, transform: &Transform) -> Result<Rc<dyn Constraint>, TransformError> {
+        transform.keep_digits()?;
+        let index = transform.target(self.index);
+        Ok(Rc::new(ParityCell::new(index, self.parity)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::constraint::testing::{all_candidates, filled};
+
+    #[test]
+    fn restricts_parity() {
+        let cell = ParityCell::new(4, Parity::Even);
+        let mut values = filled(&[]);
+        assert!(cell.check(&values));
+
+        values[4] = Value::new(3);
+        assert!(!cell.check(&values));
+
+        let mut candidates = all_candidates();
+        cell.prune(&mut candidates);
+        assert_eq!(candidates[4], ValueBitSet::from(&[2u8, 4, 6, 8][..]));
+    }
+}
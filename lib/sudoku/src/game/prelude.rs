@@ -12,3 +12,49 @@ pub use super::valuebitset::ValueBitSet;
 pub fn index(x: usize, y: usize, width: usize) -> usize {
     x + y * width
 }
+
+/// Names the cell at the specified index of a board of the specified width in `r?c?`
+/// notation, e.g. `r1c3` for the third cell of the first row.
+pub(crate) fn cell_name(index: Index, width: usize) -> String {
+    let x = (index as usize) % width;
+    let y = (index as usize) / width;
+    format!("r{}c{}", y + 1, x + 1)
+}
+
+/// Returns the orthogonal neighbors of a cell.
+pub(crate) fn orthogonal_neighbors(index: Index, width: usize, height: usize) -> Vec<Index> {
+    let (x, y) = (index as usize % width, index as usize / width);
+    let mut neighbors = Vec::with_capacity(4);
+    if x > 0 {
+        neighbors.push(index - 1);
+    }
+    if x + 1 < width {
+        neighbors.push(index + 1);
+    }
+    if y > 0 {
+        neighbors.push(index - width as Index);
+    }
+    if y + 1 < height {
+        neighbors.push(index + width as Index);
+    }
+    neighbors
+}
+
+/// Determines whether the cells form a single orthogonally connected region.
+pub(crate) fn is_connected(cells: &IndexBitSet, width: usize, height: usize) -> bool {
+    let Some(start) = cells.iter().next() else {
+        return true;
+    };
+
+    let mut reached = IndexBitSet::default().with_value(start);
+    let mut pending = vec![start];
+    while let Some(index) = pending.pop() {
+        for neighbor in orthogonal_neighbors(index, width, height) {
+            if cells.contains(neighbor) && !reached.contains(neighbor) {
+                reached.insert(neighbor);
+                pending.push(neighbor);
+            }
+        }
+    }
+    reached.len() == cells.len()
+}
@@ -0,0 +1,110 @@
+use crate::game::prelude::*;
+
+/// The number of digits.
+const DIGITS: usize = 9;
+
+/// Restricts the candidates of a line of nine cells to the digits that appear in at least
+/// one permutation of the digits that fits the candidates and shows `count` digits from the
+/// start of the line, each digit hiding the smaller digits behind it. Returns empty
+/// candidates throughout if there is no such permutation.
+///
+/// Filling the line from its start, the set of digits placed so far determines both the
+/// position and the tallest digit, so the permutations are explored over the 512 digit sets
+/// and the number of digits seen so far.
+pub(crate) fn prune_visibility(line: &[ValueBitSet], count: u8) -> Vec<ValueBitSet> {
+    let fits: Vec<u16> = line.iter().map(mask).collect();
+    let mut search = Search {
+        fits,
+        count: count as usize,
+        completes: vec![None; (1 << DIGITS) * (DIGITS + 1)],
+        allowed: vec![0; DIGITS],
+    };
+    search.completes(0, 0);
+
+    search
+        .allowed
+        .iter()
+        .map(|&allowed| {
+            let digits: Vec<u8> = (0..DIGITS as u8)
+                .filter(|digit| allowed & (1 << digit) != 0)
+                .map(|digit| digit + 1)
+                .collect();
+            ValueBitSet::from(digits.as_slice())
+        })
+        .collect()
+}
+
+struct Search {
+    /// The candidates of each cell as a bit mask, the lowest bit denoting a 1.
+    fits: Vec<u16>,
+    /// The number of digits to be seen.
+    count: usize,
+    /// Whether the line can be completed from a set of placed digits and the number of
+    /// digits seen so far, if known.
+    completes: Vec<Option<bool>>,
+    /// The digits of each cell that appear in a completed line.
+    allowed: Vec<u16>,
+}
+
+impl Search {
+    fn completes(&mut self, used: u16, seen: usize) -> bool {
+        let key = used as usize * (DIGITS + 1) + seen;
+        if let Some(completes) = self.completes[key] {
+            return completes;
+        }
+
+        let position = used.count_ones() as usize;
+        let tallest = (u16::BITS - used.leading_zeros()) as usize;
+
+        // Only the unused digits taller than the tallest one so far can still be seen.
+        let completes = if position == DIGITS {
+            seen == self.count
+        } else if seen > self.count || seen + DIGITS - tallest < self.count {
+            false
+        } else {
+            let mut completes = false;
+            let options = self.fits[position] & !used & ((1 << DIGITS) - 1);
+            for digit in (0..DIGITS).filter(|digit| options & (1 << digit) != 0) {
+                let seen = seen + usize::from(digit + 1 > tallest);
+                if self.completes(used | 1 << digit, seen) {
+                    self.allowed[position] |= 1 << digit;
+                    completes = true;
+                }
+            }
+            completes
+        };
+
+        self.completes[key] = Some(completes);
+        completes
+    }
+}
+
+fn mask(candidates: &ValueBitSet) -> u16 {
+    candidates
+        .iter()
+        .fold(0, |mask, value| mask | 1 << (value.get() - 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn prunes_permutations_by_visibility() {
+        let all = ValueBitSet::from(&[1u8, 2, 3, 4, 5, 6, 7, 8, 9][..]);
+
+        // A single visible digit means the 9 comes first, nine mean an ascending line.
+        let pruned = prune_visibility(&[all; 9], 1);
+        assert_eq!(pruned[0], ValueBitSet::from(&[9u8][..]));
+        let pruned = prune_visibility(&[all; 9], 9);
+        assert_eq!(pruned[4], ValueBitSet::from(&[5u8][..]));
+
+        // With two visible and the 9 fixed in the middle, the first digit hides the three
+        // digits after it.
+        let mut line = [all; 9];
+        line[4] = ValueBitSet::from(&[9u8][..]);
+        let pruned = prune_visibility(&line, 2);
+        assert_eq!(pruned[0], ValueBitSet::from(&[4u8, 5, 6, 7, 8][..]));
+        assert!(!pruned[5].contains(Value::new(9).unwrap()));
+    }
+}
@@ -29,6 +29,11 @@ impl State {
         self.values[index as usize]
     }
 
+    /// Returns the values of all cells in row-major order.
+    pub fn values(&self) -> &[ValueOption; 81] {
+        &self.values
+    }
+
     pub fn apply(&mut self, index: Index, value: Value) {
         self.values[index as usize] = Some(value);
     }
@@ -0,0 +1,147 @@
+use crate::game::constraint::{retain, Constraint};
+use crate::game::prelude::*;
+use crate::solver::Technique;
+use crate::transform::{Transform, TransformError};
+use crate::Game;
+use std::rc::Rc;
+
+/// A thermometer: the digits along its path strictly increase, starting from the bulb.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Thermometer {
+    /// The cells of the thermometer in order, starting with the bulb.
+    pub cells: Vec<Index>,
+}
+
+impl Thermometer {
+    /// Creates a thermometer from its cells, starting with the bulb.
+    ///
+    /// ## Panics
+    /// Panics if the thermometer is longer than nine cells, as its digits could not increase.
+    pub fn new(cells: Vec<Index>) -> Thermometer {
+        assert!(
+            cells.len() <= 9,
+            "A thermometer can hold at most nine cells"
+        );
+        Thermometer { cells }
+    }
+
+    /// Returns the range of digits the cell at the specified position along the path can take,
+    /// e.g. `2..=8` for the second cell of a thermometer of three cells.
+    pub fn digits_at(&self, position: usize) -> std::ops::RangeInclusive<u8> {
+        let below = position as u8;
+        let above = (self.cells.len() - 1 - position) as u8;
+        1 + below..=9 - above
+    }
+}
+
+impl Constraint for Thermometer {
+    fn cells(&self) -> IndexBitSet {
+        let mut cells = IndexBitSet::default();
+        for &index in self.cells.iter() {
+            cells.insert(index);
+        }
+        cells
+    }
+
+    fn check(&self, values: &[ValueOption]) -> bool {
+        let mut previous: Option<(usize, u8)> = None;
+        for (position, &index) in self.cells.iter().enumerate() {
+            let Some(value) = values[index as usize] else {
+                continue;
+            };
+
+            let value = value.get();
+            if !self.digits_at(position).contains(&value) {
+                return false;
+            }
+
+            // The cells in between need room for increasing digits as well.
+            if let Some((before, digit)) = previous {
+                if (value as usize) < digit as usize + position - before {
+                    return false;
+                }
+            }
+            previous = Some((position, value));
+        }
+        true
+    }
+
+    fn prune(&self, candidates: &mut [ValueBitSet]) {
+        // Each cell must exceed the smallest candidate of the cell before it ...
+        let mut floor = 0;
+        for &index in self.cells.iter() {
+            let cell = &mut candidates[index as usize];
+            *cell = retain(cell, |digit| digit > floor);
+            floor = cell.iter().map(|value| value.get()).min().unwrap_or(10);
+        }
+
+        // ... and fall short of the largest candidate of the cell after it.
+        let mut ceiling = 10;
+        for &index in self.cells.iter().rev() {
+            let cell = &mut candidates[index as usize];
+            *cell = retain(cell, |digit| digit < ceiling);
+            ceiling = cell.iter().map(|value| value.get()).max().unwrap_or(0);
+        }
+    }
+
+    fn technique(&self) -> Technique {
+        Technique::Thermometer
+    }
+
+    fn describe(&self, game: &Game) -> String {
+        let cells: Vec<_> = self
+            .cells
+            .iter()
+            .map(|&index| game.cell_name(index))
+            .collect();
+        format!(
+            "the thermometer {} increases from its bulb",
+            cells.join(", ")
+        )
+    }
+
+    fn transform(&self, transform: &Transform) -> Result<Rc<dyn Constraint>, TransformError> {
+        transform.keep_digits()?;
+        let cells = self.cells.iter().map(|&index| transform.target(index));
+        Ok(Rc::new(Thermometer::new(cells.collect())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::constraint::testing::{all_candidates, filled};
+
+    #[test]
+    fn checks_increasing_digits() {
+        let thermometer = Thermometer::new(vec![0, 1, 2, 3]);
+        let mut values = filled(&[]);
+        assert!(thermometer.check(&values));
+
+        values[0] = Value::new(7);
+        assert!(!thermometer.check(&values));
+
+        values[0] = Value::new(2);
+        values[3] = Value::new(4);
+        assert!(!thermometer.check(&values));
+
+        values[3] = Value::new(5);
+        assert!(thermometer.check(&values));
+
+        values[1] = Value::new(1);
+        assert!(!thermometer.check(&values));
+    }
+
+    #[test]
+    fn prunes_by_position() {
+        let thermometer = Thermometer::new(vec![0, 1, 2]);
+        let mut candidates = all_candidates();
+        candidates[1] = ValueBitSet::from(&[4u8, 5][..]);
+        thermometer.prune(&mut candidates);
+
+        assert_eq!(candidates[0], ValueBitSet::from(&[1u8, 2, 3, 4][..]));
+        assert_eq!(candidates[1], ValueBitSet::from(&[4u8, 5][..]));
+        assert_eq!(candidates[2], ValueBitSet::from(&[5u8, 6, 7, 8, 9][..]));
+        assert_eq!(thermometer.digits_at(1), 2..=8);
+    }
+}
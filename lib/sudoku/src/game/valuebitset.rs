@@ -61,6 +61,18 @@ impl ValueBitSet {
         self
     }
 
+    #[inline]
+    pub const fn with_intersection(mut self, other: &ValueBitSet) -> Self {
+        self.state &= other.state;
+        self
+    }
+
+    #[inline]
+    pub const fn without_all(mut self, other: &ValueBitSet) -> Self {
+        self.state &= !other.state;
+        self
+    }
+
     #[inline]
     pub const fn contains(&self, value: Value) -> bool {
         debug_assert!(value.get() <= 9);
@@ -112,7 +124,7 @@ impl<'a> Iterator for ValueBitSetIter<'a> {
             return None;
         }
 
-        while self.index < 9
+        while self.index <= 9
             && !self
                 .value
                 .contains(unsafe { Value::new_unchecked(self.index) })
@@ -236,4 +248,15 @@ mod tests {
         assert_eq!(iter.next(), None);
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn iter_without_nine() {
+        let a = Value::try_from(5).unwrap();
+
+        let bitset = ValueBitSet::default().with_value(a);
+        let mut iter = bitset.iter();
+
+        assert_eq!(iter.next(), Some(a));
+        assert_eq!(iter.next(), None);
+    }
 }
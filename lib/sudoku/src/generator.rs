@@ -0,0 +1,11 @@
+mod layout_generator;
+mod minimal;
+mod puzzle_generator;
+mod symmetry;
+mod targeted_generator;
+
+pub use layout_generator::LayoutGenerator;
+pub use minimal::{is_minimal, minimize, NotMinimal};
+pub use puzzle_generator::Generator;
+pub use symmetry::{Symmetry, UnsupportedSymmetry};
+pub use targeted_generator::{Budget, RatedPuzzle};
@@ -0,0 +1,164 @@
+use crate::game::prelude::{is_connected, orthogonal_neighbors};
+use crate::game::IndexBitSet;
+use crate::prelude::*;
+use crate::random::Random;
+use crate::solver::search::has_solution_within;
+use crate::{Game, GameState};
+use std::rc::Rc;
+
+/// The edge length of the (square) board.
+const SIZE: usize = 9;
+
+/// The number of attempted cell swaps between neighboring regions.
+const SWAPS: usize = 1000;
+
+/// The number of search nodes after which a layout is assumed not to admit a solution.
+/// Most layouts are solved within a few hundred nodes.
+const SEARCH_BUDGET: usize = 20_000;
+
+/// Generates random jigsaw (nonomino) layouts, i.e. connected regions of nine cells each
+/// that tile the board.
+///
+/// Like [`Generator`](crate::generator::Generator), the layout generator is deterministic
+/// for a given seed.
+///
+/// ## Example
+///
+/// ```
+/// # use sudoku::generator::{Generator, LayoutGenerator};
+/// let layout = LayoutGenerator::new(1).generate();
+/// let mut generator = Generator::new_with_groups(1, layout);
+/// assert!(generator.generate().is_some());
+/// ```
+pub struct LayoutGenerator {
+    random: Random,
+}
+
+impl LayoutGenerator {
+    pub fn new(seed: u64) -> LayoutGenerator {
+        LayoutGenerator {
+            random: Random::new(seed),
+        }
+    }
+
+    /// Generates a layout that admits at least one solution grid, as accepted by
+    /// [`Game::new_with_groups`].
+    ///
+    /// Starting from the standard 3x3 boxes, cells are repeatedly swapped between
+    /// neighboring regions as long as both regions stay connected. Layouts without a
+    /// solution, or for which a solution is not found quickly, are discarded.
+    pub fn generate(&mut self) -> Vec<Rc<IndexBitSet>> {
+        loop {
+            let groups: Vec<Rc<IndexBitSet>> = self.regions().into_iter().map(Rc::new).collect();
+
+            let empty = GameState::new(Game::new_with_groups([None; 81], groups.clone()));
+            if has_solution_within(&empty, SEARCH_BUDGET) == Some(true) {
+                return groups;
+            }
+        }
+    }
+
+    fn regions(&mut self) -> Vec<IndexBitSet> {
+        let mut regions: Vec<IndexBitSet> = Game::new_empty()
+            .groups()
+            .iter()
+            .map(|group| **group)
+            .collect();
+
+        let mut region_of = [0usize; SIZE * SIZE];
+        for (id, region) in regions.iter().enumerate() {
+            for index in region.iter() {
+                region_of[index as usize] = id;
+            }
+        }
+
+        for _ in 0..SWAPS {
+            // A cell moving into a neighboring region ...
+            let borders = border_cells(&region_of);
+            let (outgoing, target) = borders[self.random.below(borders.len())];
+            let source = region_of[outgoing as usize];
+
+            // ... in exchange for a cell of that region bordering the remaining source region.
+            let source_rest = regions[source].without_value(outgoing);
+            let incoming: Vec<Index> = regions[target]
+                .iter()
+                .filter(|&index| {
+                    orthogonal_neighbors(index, SIZE, SIZE)
+                        .into_iter()
+                        .any(|neighbor| source_rest.contains(neighbor))
+                })
+                .collect();
+            if incoming.is_empty() {
+                continue;
+            }
+            let incoming = incoming[self.random.below(incoming.len())];
+
+            let new_source = source_rest.with_value(incoming);
+            let new_target = regions[target].without_value(incoming).with_value(outgoing);
+            if !is_connected(&new_source, SIZE, SIZE) || !is_connected(&new_target, SIZE, SIZE) {
+                continue;
+            }
+
+            regions[source] = new_source;
+            regions[target] = new_target;
+            region_of[outgoing as usize] = target;
+            region_of[incoming as usize] = source;
+        }
+
+        regions
+    }
+}
+
+/// Returns all cells that border another region, together with that region.
+fn border_cells(region_of: &[usize; SIZE * SIZE]) -> Vec<(Index, usize)> {
+    let mut borders = Vec::new();
+    for index in 0..(SIZE * SIZE) as Index {
+        for neighbor in orthogonal_neighbors(index, SIZE, SIZE) {
+            let region = region_of[neighbor as usize];
+            if region != region_of[index as usize] {
+                borders.push((index, region));
+            }
+        }
+    }
+    borders
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generator::Generator;
+    use crate::solver::count_solutions;
+
+    #[test]
+    fn generates_connected_tiling() {
+        let layout = LayoutGenerator::new(1).generate();
+        assert_eq!(layout.len(), 9);
+
+        let mut covered = IndexBitSet::default();
+        for region in layout.iter() {
+            assert_eq!(region.len(), 9);
+            assert!(is_connected(region, SIZE, SIZE));
+            assert_eq!(covered.with_intersection(region), IndexBitSet::default());
+            covered.union(region);
+        }
+        assert_eq!(covered.len(), 81);
+    }
+
+    #[test]
+    fn generation_is_reproducible() {
+        let a = LayoutGenerator::new(3).generate();
+        let b = LayoutGenerator::new(3).generate();
+        let c = LayoutGenerator::new(4).generate();
+
+        assert_eq!(a, b);
+        assert_ne!(a, c);
+        assert_ne!(a, Game::new_empty().groups());
+    }
+
+    #[test]
+    fn layout_admits_unique_puzzles() {
+        let layout = LayoutGenerator::new(1).generate();
+        let game = Generator::new_with_groups(1, layout).generate().unwrap();
+        assert_eq!(count_solutions(&GameState::new(game), 2), 1);
+    }
+}
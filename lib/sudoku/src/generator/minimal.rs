@@ -0,0 +1,146 @@
+use crate::prelude::*;
+use crate::solver::count_solutions;
+use crate::{Game, GameState, Placement};
+use std::fmt::{Display, Formatter};
+
+/// Reduces a uniquely solvable game to a minimal puzzle, i.e. one where removing any
+/// further given would allow multiple solutions.
+///
+/// The givens are removed one at a time in reading order, keeping each removal that
+/// preserves a unique solution. Returns `None` if the game does not have a unique solution.
+///
+/// ## Example
+///
+/// ```
+/// # use sudoku::Game;
+/// # use sudoku::generator::{is_minimal, minimize};
+/// let game = minimize(&Game::new_example()).unwrap();
+/// assert!(is_minimal(&game).is_ok());
+/// ```
+pub fn minimize(game: &Game) -> Option<Game> {
+    let mut values = *game.fork_state().values();
+    if !is_unique(game, values) {
+        return None;
+    }
+
+    for index in 0..values.len() {
+        let Some(value) = values[index].take() else {
+            continue;
+        };
+
+        if !is_unique(game, values) {
+            values[index] = Some(value);
+        }
+    }
+
+    Some(game.with_clues(values))
+}
+
+/// Determines whether the game is minimal, i.e. uniquely solvable without redundant givens.
+///
+/// A given is redundant if the game keeps a unique solution without it. Note that removing
+/// one redundant given may render others necessary.
+pub fn is_minimal(game: &Game) -> Result<(), NotMinimal> {
+    let mut values = *game.fork_state().values();
+    if !is_unique(game, values) {
+        return Err(NotMinimal::Ambiguous);
+    }
+
+    let mut redundant = Vec::new();
+
+    for index in 0..values.len() {
+        let Some(value) = values[index].take() else {
+            continue;
+        };
+
+        if is_unique(game, values) {
+            redundant.push(Placement::new(value, index as Index));
+        }
+        values[index] = Some(value);
+    }
+
+    if redundant.is_empty() {
+        Ok(())
+    } else {
+        Err(NotMinimal::Redundant(redundant))
+    }
+}
+
+/// The error returned by [`is_minimal`] for a game that is not minimal.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum NotMinimal {
+    /// The game does not have a unique solution.
+    Ambiguous,
+    /// The game keeps a unique solution without any one of these givens.
+    Redundant(Vec<Placement>),
+}
+
+impl Display for NotMinimal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NotMinimal::Ambiguous => write!(f, "The game does not have a unique solution"),
+            NotMinimal::Redundant(givens) => {
+                write!(f, "The game has {} redundant givens", givens.len())
+            }
+        }
+    }
+}
+
+impl std::error::Error for NotMinimal {}
+
+/// Determines whether the givens have a unique solution within the layout and
+/// constraints of `game`.
+fn is_unique(game: &Game, values: [ValueOption; 81]) -> bool {
+    let state = GameState::new(game.with_clues(values));
+    count_solutions(&state, 2) == 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn minimizes_example() {
+        let game = Game::new_example();
+        let minimal = minimize(&game).unwrap();
+        assert!(is_minimal(&minimal).is_ok());
+
+        let empty = game.fork_state().empty_cells();
+        let reduced = minimal.fork_state().empty_cells();
+        assert!(empty.is_subset(&reduced));
+        assert!(reduced.len() > empty.len());
+        assert_eq!(count_solutions(&GameState::new(minimal), 2), 1);
+    }
+
+    #[test]
+    fn reports_redundant_givens() {
+        let game = Game::new_example();
+        let Err(NotMinimal::Redundant(redundant)) = is_minimal(&game) else {
+            panic!("The example has redundant givens");
+        };
+        let state = game.fork_state();
+
+        assert!(!redundant.is_empty());
+        for placement in redundant {
+            assert_eq!(
+                state.values()[placement.index as usize],
+                Some(placement.value)
+            );
+        }
+    }
+
+    #[test]
+    fn minimizes_variants() {
+        let game = Game::new_example_nonomino();
+        let minimal = minimize(&game).unwrap();
+
+        assert_eq!(minimal.groups().len(), game.groups().len());
+        assert!(is_minimal(&minimal).is_ok());
+    }
+
+    #[test]
+    fn rejects_ambiguous_games() {
+        assert!(minimize(&Game::new_empty()).is_none());
+        assert_eq!(is_minimal(&Game::new_empty()), Err(NotMinimal::Ambiguous));
+    }
+}
@@ -0,0 +1,193 @@
+use crate::game::IndexBitSet;
+use crate::generator::{Symmetry, UnsupportedSymmetry};
+use crate::prelude::*;
+use crate::random::Random;
+use crate::solver::count_solutions;
+use crate::solver::search::find_random_solution;
+use crate::{Game, GameState, State};
+use std::rc::Rc;
+
+/// Generates random puzzles that have exactly one solution.
+///
+/// The generator is deterministic: two generators created with the same seed and layout
+/// produce the same sequence of puzzles.
+///
+/// ## Example
+///
+/// ```
+/// # use sudoku::generator::Generator;
+/// # use sudoku::solver::count_solutions;
+/// # use sudoku::GameState;
+/// let mut generator = Generator::new(42);
+/// let game = generator.generate().unwrap();
+/// assert_eq!(count_solutions(&GameState::new(game), 2), 1);
+/// ```
+pub struct Generator {
+    random: Random,
+    groups: Vec<Rc<IndexBitSet>>,
+    symmetry: Symmetry,
+}
+
+impl Generator {
+    /// Initializes a generator for standard Sudoku games.
+    pub fn new(seed: u64) -> Generator {
+        Generator::new_with_groups(seed, Game::new_empty().groups().to_vec())
+    }
+
+    /// Initializes a generator for games with the specified group layout,
+    /// as accepted by [`Game::new_with_groups`].
+    pub fn new_with_groups(seed: u64, groups: Vec<Rc<IndexBitSet>>) -> Generator {
+        Generator {
+            random: Random::new(seed),
+            groups,
+            symmetry: Symmetry::None,
+        }
+    }
+
+    /// Sets the symmetry of the givens of generated puzzles.
+    ///
+    /// Only symmetries that the group layout itself has are accepted;
+    /// see [`supported_symmetries`](Self::supported_symmetries).
+    pub fn set_symmetry(&mut self, symmetry: Symmetry) -> Result<(), UnsupportedSymmetry> {
+        if !symmetry.is_supported_by(&self.groups) {
+            return Err(UnsupportedSymmetry(symmetry));
+        }
+
+        self.symmetry = symmetry;
+        Ok(())
+    }
+
+    /// Returns the symmetries that the group layout supports.
+    pub fn supported_symmetries(&self) -> Vec<Symmetry> {
+        Symmetry::supported_by(&self.groups)
+    }
+
+    /// Generates a random, completely filled grid.
+    ///
+    /// Returns `None` if the group layout does not admit any solution.
+    pub fn full_grid(&mut self) -> Option<State> {
+        let empty = GameState::new(self.game([None; 81]));
+        find_random_solution(&empty, &mut self.random)
+    }
+
+    /// Generates a puzzle with exactly one solution.
+    ///
+    /// Starting from a random full grid, the givens are removed in random order as long as the
+    /// puzzle keeps a unique solution. Givens are removed in symmetric orbits, so that the
+    /// remaining givens have the configured [`Symmetry`]. Returns `None` if the group layout
+    /// does not admit any solution.
+    pub fn generate(&mut self) -> Option<Game> {
+        let solution = self.full_grid()?;
+        let values = self.reduce(*solution.values(), |_| true);
+        Some(self.game(values))
+    }
+
+    /// Removes the givens of a full grid in random, symmetric orbits as long as the puzzle
+    /// keeps a unique solution and the remaining puzzle is accepted by `accept`.
+    pub(super) fn reduce<F>(
+        &mut self,
+        mut values: [ValueOption; 81],
+        accept: F,
+    ) -> [ValueOption; 81]
+    where
+        F: Fn(&GameState) -> bool,
+    {
+        let mut orbits = self.symmetry.orbits();
+        self.random.shuffle(&mut orbits);
+
+        for orbit in orbits {
+            let removed: Vec<(Index, ValueOption)> = orbit
+                .iter()
+                .map(|index| (index, values[index as usize].take()))
+                .collect();
+
+            let state = GameState::new(self.game(values));
+            if count_solutions(&state, 2) != 1 || !accept(&state) {
+                for (index, value) in removed {
+                    values[index as usize] = value;
+                }
+            }
+        }
+
+        values
+    }
+
+    pub(super) fn game(&self, values: [ValueOption; 81]) -> Game {
+        Game::new_with_groups(values, self.groups.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generates_unique_puzzle() {
+        let game = Generator::new(1).generate().unwrap();
+        let state = GameState::new(game);
+
+        assert!(state.validate(true));
+        assert_eq!(count_solutions(&state, 2), 1);
+    }
+
+    #[test]
+    fn generation_is_reproducible() {
+        let a = Generator::new(7).generate().unwrap();
+        let b = Generator::new(7).generate().unwrap();
+        let c = Generator::new(8).generate().unwrap();
+
+        assert!(a.fork_state() == b.fork_state());
+        assert!(a.fork_state() != c.fork_state());
+    }
+
+    #[test]
+    fn generates_full_grid() {
+        let grid = Generator::new(3).full_grid().unwrap();
+        assert!(grid.empty_cells().is_empty());
+    }
+
+    #[test]
+    fn generates_symmetric_puzzles() {
+        for symmetry in Symmetry::ALL {
+            let mut generator = Generator::new(11);
+            generator.set_symmetry(symmetry).unwrap();
+
+            let game = generator.generate().unwrap();
+            let state = GameState::new(game);
+            assert_eq!(count_solutions(&state, 2), 1);
+
+            let givens = state.empty_cells;
+            for orbit in symmetry.orbits() {
+                let first = orbit.iter().next().unwrap();
+                assert!(orbit
+                    .iter()
+                    .all(|index| givens.contains(index) == givens.contains(first)));
+            }
+        }
+    }
+
+    #[test]
+    fn rejects_symmetries_the_layout_lacks() {
+        let layout = Game::new_example_nonomino();
+        let mut generator = Generator::new_with_groups(1, layout.groups().to_vec());
+
+        for symmetry in Symmetry::ALL {
+            let supported = generator.supported_symmetries().contains(&symmetry);
+            assert_eq!(generator.set_symmetry(symmetry).is_ok(), supported);
+        }
+    }
+
+    #[test]
+    fn generates_with_custom_groups() {
+        for layout in [
+            Game::new_example_nonomino(),
+            Game::new_example_hypersudoku(),
+        ] {
+            let mut generator = Generator::new_with_groups(5, layout.groups().to_vec());
+            let state = GameState::new(generator.generate().unwrap());
+
+            assert_eq!(state.game.groups().len(), layout.groups().len());
+            assert_eq!(count_solutions(&state, 2), 1);
+        }
+    }
+}
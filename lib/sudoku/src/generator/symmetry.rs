@@ -0,0 +1,195 @@
+use crate::game::IndexBitSet;
+use crate::prelude::*;
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+use std::rc::Rc;
+
+/// The symmetry of the givens of a generated puzzle.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Symmetry {
+    /// The givens are not symmetric.
+    None,
+    /// The givens are symmetric under a rotation by 180°.
+    Rotational,
+    /// The givens mirror across the horizontal axis, i.e. the top half mirrors the bottom half.
+    MirrorHorizontal,
+    /// The givens mirror across the vertical axis, i.e. the left half mirrors the right half.
+    MirrorVertical,
+    /// The givens mirror across the main diagonal.
+    Diagonal,
+    /// The givens are symmetric under all rotations and reflections of the square.
+    Dihedral,
+}
+
+/// The edge length of the (square) board.
+const SIZE: usize = 9;
+
+type CellMap = fn(Coordinate, Coordinate, usize) -> (Coordinate, Coordinate);
+
+impl Symmetry {
+    pub const ALL: [Symmetry; 6] = [
+        Symmetry::None,
+        Symmetry::Rotational,
+        Symmetry::MirrorHorizontal,
+        Symmetry::MirrorVertical,
+        Symmetry::Diagonal,
+        Symmetry::Dihedral,
+    ];
+
+    /// Returns the symmetries the specified group layout itself has, i.e. the symmetries
+    /// that map every group onto a group of the layout.
+    pub fn supported_by(groups: &[Rc<IndexBitSet>]) -> Vec<Symmetry> {
+        Symmetry::ALL
+            .into_iter()
+            .filter(|symmetry| symmetry.is_supported_by(groups))
+            .collect()
+    }
+
+    /// Determines whether this symmetry maps every group onto a group of the layout.
+    pub fn is_supported_by(&self, groups: &[Rc<IndexBitSet>]) -> bool {
+        let layout: HashSet<IndexBitSet> = groups.iter().map(|group| **group).collect();
+        self.generators().iter().all(|map| {
+            groups.iter().all(|group| {
+                let mut mapped = IndexBitSet::default();
+                for index in group.iter() {
+                    mapped.insert(apply(*map, index));
+                }
+                layout.contains(&mapped)
+            })
+        })
+    }
+
+    /// Partitions the cells into orbits, i.e. sets of cells that map onto each other.
+    pub fn orbits(&self) -> Vec<IndexBitSet> {
+        let mut seen = IndexBitSet::default();
+        let mut orbits = Vec::new();
+        for index in 0..(SIZE * SIZE) as Index {
+            if seen.contains(index) {
+                continue;
+            }
+
+            let orbit = self.orbit(index);
+            seen.union(&orbit);
+            orbits.push(orbit);
+        }
+        orbits
+    }
+
+    /// Returns the cells the specified cell maps onto, including the cell itself.
+    pub fn orbit(&self, index: Index) -> IndexBitSet {
+        let mut orbit = IndexBitSet::default().with_value(index);
+        let mut pending = vec![index];
+        while let Some(index) = pending.pop() {
+            for map in self.generators() {
+                let mapped = apply(map, index);
+                if !orbit.contains(mapped) {
+                    orbit.insert(mapped);
+                    pending.push(mapped);
+                }
+            }
+        }
+        orbit
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Symmetry::None => "None",
+            Symmetry::Rotational => "Rotational",
+            Symmetry::MirrorHorizontal => "Horizontal Mirror",
+            Symmetry::MirrorVertical => "Vertical Mirror",
+            Symmetry::Diagonal => "Diagonal",
+            Symmetry::Dihedral => "Dihedral",
+        }
+    }
+
+    /// The cell mappings that generate this symmetry.
+    fn generators(&self) -> Vec<CellMap> {
+        match self {
+            Symmetry::None => vec![],
+            Symmetry::Rotational => vec![|x, y, n| (n - 1 - x, n - 1 - y)],
+            Symmetry::MirrorHorizontal => vec![|x, y, n| (x, n - 1 - y)],
+            Symmetry::MirrorVertical => vec![|x, y, n| (n - 1 - x, y)],
+            Symmetry::Diagonal => vec![|x, y, _| (y, x)],
+            Symmetry::Dihedral => vec![|x, y, n| (n - 1 - y, x), |x, y, n| (n - 1 - x, y)],
+        }
+    }
+}
+
+fn apply(map: CellMap, index: Index) -> Index {
+    let (x, y) = map(index as usize % SIZE, index as usize / SIZE, SIZE);
+    index_of(x, y)
+}
+
+fn index_of(x: Coordinate, y: Coordinate) -> Index {
+    index(x, y, SIZE) as Index
+}
+
+impl Display for Symmetry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The error returned when a symmetry is requested that the group layout does not have.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct UnsupportedSymmetry(pub Symmetry);
+
+impl Display for UnsupportedSymmetry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "The group layout does not have {} symmetry", self.0)
+    }
+}
+
+impl std::error::Error for UnsupportedSymmetry {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Game;
+
+    #[test]
+    fn orbits_partition_the_board() {
+        for symmetry in Symmetry::ALL {
+            let orbits = symmetry.orbits();
+            let total: usize = orbits.iter().map(|orbit| orbit.len()).sum();
+            assert_eq!(total, 81);
+        }
+    }
+
+    #[test]
+    fn orbit_sizes() {
+        let corner = index_of(0, 0);
+        let center = index_of(4, 4);
+
+        assert_eq!(Symmetry::None.orbit(corner).len(), 1);
+        assert_eq!(Symmetry::Rotational.orbit(corner).len(), 2);
+        assert_eq!(Symmetry::Diagonal.orbit(corner).len(), 1);
+        assert_eq!(Symmetry::Dihedral.orbit(corner).len(), 4);
+        assert_eq!(Symmetry::Dihedral.orbit(index_of(1, 0)).len(), 8);
+        assert_eq!(Symmetry::Dihedral.orbit(center).len(), 1);
+    }
+
+    #[test]
+    fn standard_layout_supports_all_symmetries() {
+        let game = Game::new_empty();
+        assert_eq!(
+            Symmetry::supported_by(game.groups()),
+            Symmetry::ALL.to_vec()
+        );
+
+        let hyper = Game::new_example_hypersudoku();
+        assert_eq!(
+            Symmetry::supported_by(hyper.groups()),
+            Symmetry::ALL.to_vec()
+        );
+    }
+
+    #[test]
+    fn nonomino_layout_restricts_symmetries() {
+        let game = Game::new_example_nonomino();
+        let supported = Symmetry::supported_by(game.groups());
+
+        assert!(supported.contains(&Symmetry::None));
+        assert!(supported.len() < Symmetry::ALL.len());
+    }
+}
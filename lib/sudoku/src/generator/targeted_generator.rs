@@ -0,0 +1,122 @@
+use crate::generator::Generator;
+use crate::solver::{explain, solve_with, Difficulty, Technique, Tier};
+use crate::{Game, GameState};
+use std::time::{Duration, Instant};
+
+/// Limits the effort spent on generating a puzzle of a specific difficulty.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Budget {
+    /// Gives up after the specified number of candidate puzzles.
+    Attempts(usize),
+    /// Gives up once the specified time has elapsed.
+    Time(Duration),
+}
+
+/// A generated puzzle together with its rating.
+pub struct RatedPuzzle {
+    pub game: Game,
+    pub difficulty: Difficulty,
+    /// The techniques the logical solve of the puzzle requires, easiest first.
+    pub techniques: Vec<Technique>,
+}
+
+impl Budget {
+    fn allows(&self, attempts: usize, started: Instant) -> bool {
+        match self {
+            Budget::Attempts(limit) => attempts < *limit,
+            Budget::Time(limit) => started.elapsed() < *limit,
+        }
+    }
+}
+
+impl Generator {
+    /// Generates a puzzle of the specified difficulty tier, e.g. [`Tier::Easy`] for a puzzle
+    /// that needs hidden singles but nothing harder.
+    ///
+    /// Givens are only removed as long as the puzzle remains solvable with the techniques
+    /// of the target tier; candidate puzzles that turn out to be easier than requested are
+    /// discarded. Returns `None` if no matching puzzle was found within the budget.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// # use sudoku::generator::{Budget, Generator};
+    /// # use sudoku::solver::{Technique, Tier};
+    /// let mut generator = Generator::new(42);
+    /// let puzzle = generator
+    ///     .generate_with_difficulty(Tier::Easy, Budget::Attempts(10))
+    ///     .unwrap();
+    /// assert_eq!(puzzle.techniques, vec![Technique::HiddenSingle]);
+    /// ```
+    pub fn generate_with_difficulty(
+        &mut self,
+        target: Tier,
+        budget: Budget,
+    ) -> Option<RatedPuzzle> {
+        let started = Instant::now();
+        let techniques = target.techniques();
+
+        let mut attempts = 0;
+        while budget.allows(attempts, started) {
+            attempts += 1;
+
+            let solution = self.full_grid()?;
+            let values = self.reduce(*solution.values(), |state| {
+                target == Tier::Diabolical || solve_with(state, &techniques).is_solved()
+            });
+
+            let log = explain(&GameState::new(self.game(values)));
+            let difficulty = Difficulty::from(&log);
+            if difficulty.tier == target {
+                return Some(RatedPuzzle {
+                    game: self.game(values),
+                    difficulty,
+                    techniques: log.techniques(),
+                });
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::count_solutions;
+
+    #[test]
+    fn generates_easy_puzzle() {
+        let mut generator = Generator::new(3);
+        let puzzle = generator
+            .generate_with_difficulty(Tier::Easy, Budget::Attempts(10))
+            .unwrap();
+
+        assert_eq!(puzzle.difficulty.tier, Tier::Easy);
+        assert_eq!(puzzle.techniques, vec![Technique::HiddenSingle]);
+        assert_eq!(count_solutions(&GameState::new(puzzle.game), 2), 1);
+    }
+
+    #[test]
+    fn generates_puzzle_requiring_harder_techniques() {
+        let mut generator = Generator::new(5);
+        let puzzle = generator
+            .generate_with_difficulty(Tier::Hard, Budget::Attempts(20))
+            .unwrap();
+
+        assert_eq!(puzzle.difficulty.tier, Tier::Hard);
+        assert!(!puzzle.difficulty.requires_guessing());
+        assert!(puzzle
+            .techniques
+            .iter()
+            .all(|technique| Tier::Hard.techniques().contains(technique)));
+        assert!(solve_with(&GameState::new(puzzle.game), &Tier::Hard.techniques()).is_solved());
+    }
+
+    #[test]
+    fn gives_up_when_budget_is_exhausted() {
+        let mut generator = Generator::new(1);
+        let puzzle = generator.generate_with_difficulty(Tier::Expert, Budget::Attempts(0));
+        assert!(puzzle.is_none());
+    }
+}
@@ -3,14 +3,41 @@ extern crate core;
 #[macro_use]
 mod macros;
 mod game;
+pub mod generator;
+pub mod multigrid;
+mod random;
 pub mod solver;
+pub mod transform;
 pub mod visualization;
 
+pub use game::AntiKing;
+pub use game::AntiKnight;
+pub use game::Arrow;
+pub use game::Cage;
+pub use game::Constraint;
+pub use game::Diagonal;
+pub use game::Edge;
 pub use game::Game;
 pub use game::GameState;
+pub use game::House;
+pub use game::HouseKind;
 pub use game::IndexBitSet;
+pub use game::Inequality;
+pub use game::LayoutError;
+pub use game::Line;
+pub use game::LineKind;
+pub use game::LittleKiller;
+pub use game::Marker;
+pub use game::NegativeMarker;
+pub use game::NonConsecutive;
+pub use game::OutsideClue;
+pub use game::OutsideKind;
+pub use game::Parity;
+pub use game::ParityCell;
 pub use game::Placement;
+pub use game::Side;
 pub use game::State;
+pub use game::Thermometer;
 pub use game::ValueBitSet;
 
 pub mod prelude {
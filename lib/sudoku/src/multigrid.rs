@@ -0,0 +1,370 @@
+//! Puzzles made of several overlapping 9x9 grids that share boxes, e.g. Samurai, Butterfly
+//! and Twodoku.
+//!
+//! The board of a [`MultiGrid`] is larger than the 81 cells of a [`Game`], and its houses
+//! cover only part of it: every grid contributes its rows, columns and boxes, and the cells
+//! of a shared box belong to each grid covering them. Cells outside of all grids are not part
+//! of the puzzle.
+//!
+//! ## Example
+//!
+//! ```
+//! # use sudoku::multigrid::MultiGrid;
+//! let puzzle = MultiGrid::new_example_samurai();
+//! assert_eq!(puzzle.count_solutions(2), 1);
+//!
+//! let solution = puzzle.solve().unwrap();
+//! assert!(solution.validate(false));
+//! ```
+
+use crate::prelude::*;
+use crate::solver::search::Search;
+use crate::solver::{solve_with, LogicalSolution, Technique};
+use crate::{Game, GameState, ValueBitSet};
+use std::fmt::{Display, Formatter};
+
+/// The edge length of a grid.
+const SIZE: usize = 9;
+
+/// The edge length of a box.
+const BOX: usize = 3;
+
+/// Five grids: four in the corners, each sharing a box with the one in the center.
+const SAMURAI: [(usize, usize); 5] = [(0, 0), (12, 0), (6, 6), (0, 12), (12, 12)];
+
+/// Four grids overlapping in a square of two by two boxes per pair of neighbors.
+const BUTTERFLY: [(usize, usize); 4] = [(0, 0), (3, 0), (0, 3), (3, 3)];
+
+/// Two grids sharing the bottom right box of the first one.
+const TWODOKU: [(usize, usize); 2] = [(0, 0), (6, 6)];
+
+/// A puzzle made of several 9x9 grids placed on a larger board.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct MultiGrid {
+    width: usize,
+    height: usize,
+    /// The top left cell of every grid as (x, y) coordinates on the board.
+    origins: Vec<(usize, usize)>,
+    /// The values of the board in row-major order. Cells outside of the grids remain empty.
+    values: Vec<ValueOption>,
+}
+
+impl MultiGrid {
+    /// Creates a puzzle from the top left cells of its grids and its clues.
+    ///
+    /// The clues list the cells covered by any grid in row-major order, skipping the cells
+    /// outside of the grids, e.g. `"53..7...."`. Digits denote values, `.` or `0` denote empty
+    /// cells and whitespace is ignored, so the output of [`Display`] can be read back.
+    ///
+    /// ## Panics
+    /// Panics if there are no grids, if a grid is not aligned to the boxes of the board (which
+    /// ensures that grids overlap in whole boxes only), if two grids coincide, or if the number
+    /// of clues does not match the number of covered cells.
+    pub fn new(origins: Vec<(usize, usize)>, clues: &str) -> MultiGrid {
+        assert!(!origins.is_empty(), "A puzzle needs at least one grid");
+        for (i, &(x, y)) in origins.iter().enumerate() {
+            assert!(
+                x % BOX == 0 && y % BOX == 0,
+                "Grid {} is not aligned to the boxes of the board",
+                i + 1
+            );
+            assert!(
+                !origins[..i].contains(&(x, y)),
+                "Grid {} coincides with another grid",
+                i + 1
+            );
+        }
+
+        let width = origins.iter().map(|&(x, _)| x + SIZE).max().unwrap();
+        let height = origins.iter().map(|&(_, y)| y + SIZE).max().unwrap();
+        let mut puzzle = MultiGrid {
+            width,
+            height,
+            origins,
+            values: vec![None; width * height],
+        };
+
+        let covered: Vec<usize> = (0..width * height)
+            .filter(|&index| puzzle.covers(index))
+            .collect();
+        let mut cells = covered.into_iter();
+        for c in clues.chars().filter(|c| !c.is_whitespace()) {
+            let index = cells.next().expect("Too many clues for the grids");
+            puzzle.values[index] = match c {
+                '.' | '0' => None,
+                '1'..='9' => Value::new(c as u8 - b'0'),
+                _ => panic!("Invalid cell value: {}", c),
+            };
+        }
+        assert!(cells.next().is_none(), "Too few clues for the grids");
+        puzzle
+    }
+
+    /// Creates a Samurai puzzle: five grids, four of them sharing a corner box with the
+    /// grid in the center.
+    pub fn samurai(clues: &str) -> MultiGrid {
+        MultiGrid::new(SAMURAI.to_vec(), clues)
+    }
+
+    /// Creates a Butterfly puzzle: four grids on a 12x12 board, neighbors sharing six boxes.
+    pub fn butterfly(clues: &str) -> MultiGrid {
+        MultiGrid::new(BUTTERFLY.to_vec(), clues)
+    }
+
+    /// Creates a Twodoku puzzle: two grids sharing a single box.
+    pub fn twodoku(clues: &str) -> MultiGrid {
+        MultiGrid::new(TWODOKU.to_vec(), clues)
+    }
+
+    /// A Samurai puzzle whose center grid can only be solved with the digits its corner
+    /// boxes receive from the outer grids.
+    pub fn new_example_samurai() -> MultiGrid {
+        MultiGrid::samurai(
+            "
+            123......   ....49..5
+            78...3456   ......84.
+            4........   .4.6.5...
+            .1.......   2........
+            ..5.....7   5...1.7..
+            ...84...2   .79..4..2
+            ....6.......1.....4.7
+            ...2.1...1.....2.....
+            .6....2............3.
+                  ..6.7....
+                  .4.9..6..
+                  .8.3.....
+            4...5......4......4..
+            ..6.....3....8.....2.
+            .......6..8.........9
+            ....4....   ....5.3..
+            ..3.1....   34.9....7
+            76......9   .....6...
+            2...64.9.   8.273....
+            .....1..8   6...91...
+            .8.59...1   .9..8...1
+            ",
+        )
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the number of grids.
+    pub fn grid_count(&self) -> usize {
+        self.origins.len()
+    }
+
+    /// Determines whether the cell is part of any grid.
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        x < self.width && y < self.height && self.covers(x + y * self.width)
+    }
+
+    pub fn cell(&self, x: usize, y: usize) -> ValueOption {
+        self.values[x + y * self.width]
+    }
+
+    /// Returns the specified grid as a standard game, with its current values as clues.
+    pub fn grid(&self, grid: usize) -> Game {
+        let mut values = [None; SIZE * SIZE];
+        for (i, value) in values.iter_mut().enumerate() {
+            *value = self.values[self.board_index(grid, i)];
+        }
+        Game::new(values)
+    }
+
+    /// Validates the board; see [`GameState::validate`].
+    pub fn validate(&self, allow_empty: bool) -> bool {
+        let houses_valid = self.houses().iter().all(|house| {
+            let mut seen = ValueBitSet::default();
+            house
+                .iter()
+                .filter_map(|&index| self.values[index])
+                .all(|value| {
+                    let repeated = seen.contains(value);
+                    seen.insert(value);
+                    !repeated
+                })
+        });
+
+        houses_valid
+            && (allow_empty
+                || (0..self.values.len())
+                    .all(|index| !self.covers(index) || self.values[index].is_some()))
+    }
+
+    /// Counts the solutions of the puzzle, stopping once `limit` solutions were found.
+    ///
+    /// A puzzle is uniquely solvable if `count_solutions(2)` returns `1`.
+    pub fn count_solutions(&self, limit: usize) -> usize {
+        if limit == 0 || !self.validate(true) {
+            return 0;
+        }
+
+        let mut solutions = Vec::new();
+        let search = Search::with_houses(self.houses(), self.values.len());
+        search.run(&mut self.values.clone(), limit, &mut solutions, None);
+        solutions.len()
+    }
+
+    /// Solves the puzzle. Returns `None` if it has no solution.
+    ///
+    /// The grids are solved logically one at a time, and the digits placed in a shared box
+    /// carry over into the neighboring grids until no grid makes further progress. Whatever
+    /// remains is resolved by searching the whole board.
+    pub fn solve(&self) -> Option<MultiGrid> {
+        let mut puzzle = self.clone();
+        if !puzzle.validate(true) || !puzzle.propagate() {
+            return None;
+        }
+
+        let mut solutions = Vec::new();
+        let search = Search::with_houses(puzzle.houses(), puzzle.values.len());
+        search.run(&mut puzzle.values.clone(), 1, &mut solutions, None);
+        puzzle.values = solutions.pop()?;
+        Some(puzzle)
+    }
+
+    /// Solves every grid with the logical techniques and copies its placements onto the board,
+    /// repeating until no grid makes progress. Returns `false` if a grid has no solution.
+    fn propagate(&mut self) -> bool {
+        loop {
+            let mut progress = false;
+            for grid in 0..self.origins.len() {
+                let solution = solve_with(&GameState::new(self.grid(grid)), &Technique::ALL);
+                if let LogicalSolution::Stuck { candidates, .. } = &solution {
+                    if candidates.values().any(|options| options.is_empty()) {
+                        return false;
+                    }
+                }
+
+                let state = solution.state();
+                if !state.validate(true) {
+                    return false;
+                }
+
+                for (i, &value) in state.state.values().iter().enumerate() {
+                    let index = self.board_index(grid, i);
+                    if self.values[index] != value {
+                        self.values[index] = value;
+                        progress = true;
+                    }
+                }
+            }
+
+            if !progress {
+                return true;
+            }
+        }
+    }
+
+    /// Returns the cells of the rows, columns and boxes of every grid as board indexes.
+    /// A box shared by several grids is listed once.
+    fn houses(&self) -> Vec<Vec<usize>> {
+        let mut houses: Vec<Vec<usize>> = Vec::new();
+        for grid in 0..self.origins.len() {
+            for i in 0..SIZE {
+                let row = (0..SIZE).map(|x| self.board_index(grid, x + i * SIZE));
+                let column = (0..SIZE).map(|y| self.board_index(grid, i + y * SIZE));
+                let (left, top) = (i % BOX * BOX, i / BOX * BOX);
+                let r#box = (0..SIZE).map(|j| {
+                    let (x, y) = (left + j % BOX, top + j / BOX);
+                    self.board_index(grid, x + y * SIZE)
+                });
+
+                for house in [row.collect(), column.collect(), r#box.collect()] {
+                    if !houses.contains(&house) {
+                        houses.push(house);
+                    }
+                }
+            }
+        }
+        houses
+    }
+
+    /// Maps an index within the specified grid to an index on the board.
+    fn board_index(&self, grid: usize, index: usize) -> usize {
+        let (left, top) = self.origins[grid];
+        let (x, y) = (left + index % SIZE, top + index / SIZE);
+        x + y * self.width
+    }
+
+    /// Determines whether the board index lies within any grid.
+    fn covers(&self, index: usize) -> bool {
+        let (x, y) = (index % self.width, index / self.width);
+        self.origins
+            .iter()
+            .any(|&(left, top)| (left..left + SIZE).contains(&x) && (top..top + SIZE).contains(&y))
+    }
+}
+
+/// Prints the board row by row, `.` denoting empty cells and blanks the cells outside of the
+/// grids.
+impl Display for MultiGrid {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.height {
+            let mut line = String::new();
+            for x in 0..self.width {
+                line.push(match (self.contains(x, y), self.cell(x, y)) {
+                    (false, _) => ' ',
+                    (true, None) => '.',
+                    (true, Some(value)) => (b'0' + value.get()) as char,
+                });
+            }
+            writeln!(f, "{}", line.trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shared_boxes_are_single_houses() {
+        let twodoku = MultiGrid::twodoku(&".".repeat(2 * 81 - 9));
+        assert_eq!((twodoku.width(), twodoku.height()), (15, 15));
+        assert_eq!(twodoku.houses().len(), 2 * 27 - 1);
+
+        let samurai = MultiGrid::samurai(&".".repeat(5 * 81 - 4 * 9));
+        assert_eq!(samurai.houses().len(), 5 * 27 - 4);
+        assert!(!samurai.contains(10, 0));
+        assert!(samurai.contains(10, 7));
+    }
+
+    #[test]
+    fn solves_samurai_through_shared_boxes() {
+        let puzzle = MultiGrid::new_example_samurai();
+        let center = GameState::new(puzzle.grid(2));
+        assert_eq!(crate::solver::count_solutions(&center, 2), 2);
+
+        // Digits placed in the corner boxes of the outer grids carry over into the center.
+        let mut propagated = puzzle.clone();
+        assert!(propagated.propagate());
+        let open = |grid: &Game| grid.fork_state().empty_cells().len();
+        assert!(open(&propagated.grid(2)) < open(&puzzle.grid(2)));
+
+        let solution = puzzle.solve().unwrap();
+        assert!(solution.validate(false));
+        assert_eq!(solution.count_solutions(2), 1);
+    }
+
+    #[test]
+    fn display_round_trips() {
+        let puzzle = MultiGrid::new_example_samurai();
+        let text = puzzle.to_string();
+        assert!(text.starts_with("123......   ....49..5\n"));
+        assert_eq!(MultiGrid::samurai(&text), puzzle);
+    }
+
+    #[test]
+    #[should_panic(expected = "not aligned")]
+    fn rejects_misaligned_grids() {
+        MultiGrid::new(vec![(0, 0), (4, 4)], "");
+    }
+}
@@ -1,5 +1,20 @@
 pub(self) mod candidates;
+mod chaos;
+mod deduction;
 mod default_solver;
+mod difficulty;
+mod explain;
+mod logic;
+mod logical_solver;
+pub(crate) mod search;
 pub(self) mod steps;
+mod technique;
 
+pub use chaos::{count_chaos_solutions, solve_chaos, ChaosSolution};
+pub use deduction::Deduction;
 pub use default_solver::solve;
+pub use difficulty::{rate, Difficulty, Tier};
+pub use explain::{explain, Guess, SolveLog, SolveStep};
+pub use logical_solver::{solve_with, LogicalSolution};
+pub use search::count_solutions;
+pub use technique::Technique;
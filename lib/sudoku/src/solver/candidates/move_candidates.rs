@@ -19,8 +19,4 @@ impl MoveCandidates {
             moves: HashSet::from_iter(moves),
         }
     }
-
-    pub fn is_trivial(&self) -> bool {
-        self.moves.len() == 1
-    }
 }
@@ -1,4 +1,4 @@
-use crate::game::Placement;
+use crate::game::{Placement, ValueBitSet};
 use crate::prelude::*;
 use crate::solver::candidates::MoveCandidates;
 use std::collections::{HashMap, HashSet};
@@ -50,6 +50,29 @@ impl SetOfMoveCandidates {
         self.moves.len() > 0
     }
 
+    /// Removes all candidates of the cell at the specified index,
+    /// e.g. after a value was placed in it.
+    pub fn forget_index(&mut self, index: Index) {
+        self.moves.remove(&index);
+    }
+
+    pub fn contains(&self, candidate: &Placement) -> bool {
+        self.moves
+            .get(&candidate.index)
+            .is_some_and(|moves| moves.contains(candidate))
+    }
+
+    /// Returns the candidate values of the cell at the specified index.
+    pub fn values(&self, index: Index) -> ValueBitSet {
+        let mut values = ValueBitSet::default();
+        if let Some(moves) = self.moves.get(&index) {
+            for candidate in moves {
+                values.insert(candidate.value);
+            }
+        }
+        values
+    }
+
     pub fn iter<'a>(&'a self) -> impl Iterator<Item = MoveCandidates> + 'a {
         self.moves.iter().map(|(key, value)| {
             MoveCandidates::from_iter(key.clone(), value.iter().map(|x| x.clone()))
@@ -0,0 +1,236 @@
+use crate::game::prelude::orthogonal_neighbors;
+use crate::prelude::*;
+use crate::solver::search::Search;
+use crate::{GameState, State};
+
+/// The edge length of the (square) board, and the size of every region.
+const SIZE: usize = 9;
+
+/// The number of search nodes after which a partial layout is assumed to admit a solution.
+const SEARCH_BUDGET: usize = 50;
+
+/// The solution of a chaos construction: the digits together with the deduced regions.
+#[derive(Clone, Eq, PartialEq)]
+pub struct ChaosSolution {
+    pub state: State,
+    /// The nine regions, ordered by their first cell in row-major order.
+    pub groups: Vec<IndexBitSet>,
+}
+
+/// Solves a chaos construction, i.e. a game whose regions are unknown: the board is
+/// partitioned into nine connected regions of nine cells each along with filling in the digits,
+/// such that every row, column and region holds each digit once.
+///
+/// The groups of the game are ignored, while all other constraints apply. Returns `None` if
+/// there is no solution.
+///
+/// Note that the rows and columns alone rarely settle the layout: the columns of any solution
+/// form a valid layout themselves. Which of the fitting layouts is returned is unspecified.
+pub fn solve_chaos(state: &GameState) -> Option<ChaosSolution> {
+    find_chaos_solutions(state, 1).pop()
+}
+
+/// Counts the solutions of a chaos construction, stopping once `limit` solutions were found.
+/// Each combination of digits and regions counts as a solution of its own.
+pub fn count_chaos_solutions(state: &GameState, limit: usize) -> usize {
+    find_chaos_solutions(state, limit).len()
+}
+
+fn find_chaos_solutions(state: &GameState, limit: usize) -> Vec<ChaosSolution> {
+    if limit == 0 || !state.validate(true) {
+        return Vec::new();
+    }
+
+    let mut layout = Layout {
+        state,
+        limit,
+        regions: Vec::new(),
+        solutions: Vec::new(),
+    };
+    layout.place(IndexBitSet::default());
+    layout.solutions
+}
+
+/// Enumerates the partitions of the board into regions, one region at a time, and solves the
+/// digits of each complete partition.
+///
+/// Each region grows from the first cell not yet covered, so that every partition is
+/// enumerated exactly once. The regions grow by Redelmeier's method: a cell is either added or
+/// excluded for good, and only the neighbors of added cells are considered.
+struct Layout<'a> {
+    state: &'a GameState,
+    limit: usize,
+    /// The regions placed so far.
+    regions: Vec<IndexBitSet>,
+    solutions: Vec<ChaosSolution>,
+}
+
+impl<'a> Layout<'a> {
+    fn place(&mut self, covered: IndexBitSet) {
+        let Some(first) = (0..(SIZE * SIZE) as Index).find(|&index| !covered.contains(index))
+        else {
+            self.solve_digits();
+            return;
+        };
+
+        let seen = covered.with_value(first);
+        self.grow(
+            IndexBitSet::default(),
+            ValueBitSet::default(),
+            vec![first],
+            seen,
+            covered,
+        );
+    }
+
+    /// Grows the region by each of the untried cells in turn. The seen cells were considered
+    /// for the region before, or are covered by other regions.
+    fn grow(
+        &mut self,
+        region: IndexBitSet,
+        digits: ValueBitSet,
+        mut untried: Vec<Index>,
+        seen: IndexBitSet,
+        covered: IndexBitSet,
+    ) {
+        while let Some(index) = untried.pop() {
+            if self.solutions.len() >= self.limit {
+                return;
+            }
+
+            // A region cannot hold a digit twice.
+            let mut digits = digits;
+            if let Some(value) = self.state.state.values()[index as usize] {
+                if digits.contains(value) {
+                    continue;
+                }
+                digits.insert(value);
+            }
+
+            let region = region.with_value(index);
+            if region.len() == SIZE {
+                let mut covered = covered;
+                covered.union(&region);
+                self.regions.push(region);
+                if self.admits_regions(&covered) {
+                    self.place(covered);
+                }
+                self.regions.pop();
+                continue;
+            }
+
+            let mut untried = untried.clone();
+            let mut seen = seen;
+            for neighbor in orthogonal_neighbors(index, SIZE, SIZE) {
+                if !seen.contains(neighbor) {
+                    seen.insert(neighbor);
+                    untried.push(neighbor);
+                }
+            }
+            self.grow(region, digits, untried, seen, covered);
+        }
+    }
+
+    /// Determines whether the uncovered cells may still be partitioned into regions: they must
+    /// fall apart into areas of whole regions, and the digits must remain solvable with the
+    /// regions placed so far.
+    fn admits_regions(&self, covered: &IndexBitSet) -> bool {
+        let mut reached = *covered;
+        for start in 0..(SIZE * SIZE) as Index {
+            if reached.contains(start) {
+                continue;
+            }
+
+            reached.insert(start);
+            let mut area = 1;
+            let mut pending = vec![start];
+            while let Some(index) = pending.pop() {
+                for neighbor in orthogonal_neighbors(index, SIZE, SIZE) {
+                    if !reached.contains(neighbor) {
+                        reached.insert(neighbor);
+                        pending.push(neighbor);
+                        area += 1;
+                    }
+                }
+            }
+            if area % SIZE != 0 {
+                return false;
+            }
+        }
+
+        let search = self.search();
+        search.solvable_within(*self.state.state.values(), SEARCH_BUDGET) != Some(false)
+    }
+
+    fn solve_digits(&mut self) {
+        let search = self.search();
+        let mut values = *self.state.state.values();
+        let mut solutions = Vec::new();
+        search.run(
+            &mut values,
+            self.limit - self.solutions.len(),
+            &mut solutions,
+            None,
+        );
+
+        for values in solutions {
+            let values: [ValueOption; SIZE * SIZE] =
+                values.try_into().expect("A standard game has 81 cells");
+            self.solutions.push(ChaosSolution {
+                state: State::new(values),
+                groups: self.regions.clone(),
+            });
+        }
+    }
+
+    /// Returns a search over the rows, columns and constraints of the game, and the regions
+    /// placed so far.
+    fn search(&self) -> Search {
+        let mut search = Search::new(self.state);
+        for region in self.regions.iter() {
+            search.add_house(region.iter().map(|index| index as usize).collect());
+        }
+        search
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game::prelude::is_connected;
+    use crate::solver::solve;
+    use crate::Game;
+
+    #[test]
+    fn deduces_regions_with_digits() {
+        let clues = Game::new_example_nonomino().fork_state();
+        let state = GameState::new(Game::new_chaos(*clues.values()));
+
+        let solution = solve_chaos(&state).unwrap();
+        assert_eq!(solution.groups.len(), 9);
+        for region in solution.groups.iter() {
+            assert!(is_connected(region, SIZE, SIZE));
+            let digits: Vec<Value> = region
+                .iter()
+                .filter_map(|index| solution.state.values()[index as usize])
+                .collect();
+            assert_eq!(ValueBitSet::from(digits.as_slice()).len(), 9);
+        }
+
+        // The default solver returns the deduced layout as the groups of its game.
+        let solved = solve(&state);
+        assert!(!solved.game.is_chaos());
+        assert!(solved.state.values() == solution.state.values());
+        let groups: Vec<IndexBitSet> = solved.game.groups().iter().map(|group| **group).collect();
+        assert_eq!(groups, solution.groups);
+        assert!(solved.validate(false));
+    }
+
+    #[test]
+    fn complete_grid_admits_several_layouts() {
+        // The columns always make for a layout, as do the regions of the original puzzle.
+        let solved = solve(&GameState::new(Game::new_example_nonomino()));
+        let state = GameState::new(Game::new_chaos(*solved.state.values()));
+        assert_eq!(count_chaos_solutions(&state, 2), 2);
+    }
+}
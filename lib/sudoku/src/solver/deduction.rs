@@ -0,0 +1,64 @@
+use crate::game::IndexBitSet;
+use crate::solver::Technique;
+use crate::{Game, Placement};
+
+/// A single logical step made by one of the solving techniques.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Deduction {
+    pub technique: Technique,
+    /// The values placed by this step.
+    pub placements: Vec<Placement>,
+    /// The candidates eliminated by this step.
+    pub eliminations: Vec<Placement>,
+    /// A human-readable explanation of why the step holds.
+    pub reason: String,
+}
+
+impl Deduction {
+    pub fn placement(technique: Technique, placement: Placement, reason: String) -> Deduction {
+        Deduction {
+            technique,
+            placements: vec![placement],
+            eliminations: Vec::new(),
+            reason,
+        }
+    }
+
+    pub fn elimination(
+        technique: Technique,
+        eliminations: Vec<Placement>,
+        reason: String,
+    ) -> Deduction {
+        Deduction {
+            technique,
+            placements: Vec::new(),
+            eliminations,
+            reason,
+        }
+    }
+
+    /// Returns the cells changed by this step.
+    pub fn cells(&self) -> IndexBitSet {
+        let mut cells = IndexBitSet::default();
+        for placement in self.placements.iter().chain(self.eliminations.iter()) {
+            cells.insert(placement.index);
+        }
+        cells
+    }
+
+    /// Describes the effect of this step, e.g. `r1c3 = 4` or `r2c5 ≠ 7, r2c6 ≠ 7`.
+    pub fn describe_effect(&self, game: &Game) -> String {
+        let placements = self
+            .placements
+            .iter()
+            .map(|p| format!("{} = {}", game.cell_name(p.index), p.value));
+        let eliminations = self
+            .eliminations
+            .iter()
+            .map(|p| format!("{} ≠ {}", game.cell_name(p.index), p.value));
+        placements
+            .chain(eliminations)
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
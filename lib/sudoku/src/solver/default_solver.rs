@@ -1,18 +1,27 @@
 use log::debug;
 use std::collections::HashSet;
 use std::iter::FromIterator;
-use std::iter::IntoIterator;
+use std::rc::Rc;
 
-use crate::game::{CollectType, Placement};
 use crate::prelude::*;
-use crate::solver::candidates::{find_move_candidates, MoveCandidates, SetOfMoveCandidates};
-use crate::solver::steps::{hidden_singles, lone_singles};
+use crate::solver::candidates::{find_move_candidates, MoveCandidates};
+use crate::solver::logic::{apply_deduction, is_solvable, next_deduction};
+use crate::solver::{solve_chaos, Technique};
 use crate::GameState;
 
+/// Solves the game by applying all known techniques, branching on the remaining candidates
+/// whenever the techniques make no further progress.
+///
+/// Use [`solve_with`](crate::solver::solve_with) to solve with a restricted set of techniques
+/// and without branching.
+///
+/// A [chaos](crate::Game::new_chaos) construction is solved by
+/// [`solve_chaos`](crate::solver::solve_chaos) instead; the game of the returned state then
+/// holds the deduced regions as its groups. Without a solution, the state is returned as is.
 pub fn solve(game: &GameState) -> GameState {
-    // Strategies to apply in the given order.
-    // TODO: Apply naked twins strategy
-    let strategies: Vec<StrategyFn> = vec![lone_singles, hidden_singles];
+    if game.game.is_chaos() {
+        return solve_chaos_game(game);
+    }
 
     let valid_symbols = collect_valid_symbols(game);
     let initial_candidates = find_move_candidates(&game, &valid_symbols);
@@ -38,21 +47,16 @@ pub fn solve(game: &GameState) -> GameState {
             return state;
         }
 
-        let mut applied_some = true;
-        while applied_some {
-            applied_some = false;
-
+        // Apply the logical techniques until none of them makes progress.
+        while let Some(deduction) = next_deduction(&state, &candidates, &Technique::ALL) {
             debug!("  - {} candidates remaining", candidates.total_len());
+            debug!("  - {}: {}", deduction.technique, deduction.reason);
+            apply_deduction(&mut state, &mut candidates, &deduction);
 
-            for strategy in strategies.iter() {
-                applied_some |=
-                    match apply_simple_strategy_repeatedly(strategy, &mut state, &mut candidates) {
-                        Ok(applied) => applied,
-                        Err(_) => {
-                            // branch has become invalid
-                            continue 'stack;
-                        }
-                    };
+            // If an invalid move was made here, leave this branch.
+            if !state.validate(true) {
+                debug!("  ! Branch is invalid.");
+                continue 'stack;
             }
         }
 
@@ -102,72 +106,21 @@ pub fn solve(game: &GameState) -> GameState {
     unreachable!()
 }
 
-pub type StrategyFn = fn(&mut GameState, &SetOfMoveCandidates) -> Vec<Placement>;
-
-fn apply_simple_strategy_repeatedly(
-    strategy: &StrategyFn,
-    state: &mut GameState,
-    candidates: &mut SetOfMoveCandidates,
-) -> Result<bool, bool> {
-    let mut applied_some = false;
-    loop {
-        match apply_simple_strategy_once(strategy, state, candidates) {
-            Ok(true) => {
-                applied_some = true;
-                continue;
+fn solve_chaos_game(game: &GameState) -> GameState {
+    let Some(solution) = solve_chaos(game) else {
+        return game.clone();
+    };
+
+    let groups = solution.groups.into_iter().map(Rc::new).collect();
+    let mut solved = GameState::new(game.game.with_groups(groups));
+    for (index, value) in solution.state.values().iter().enumerate() {
+        if let Some(value) = value {
+            if solved.state.values()[index].is_none() {
+                solved.apply(index as Index, *value);
             }
-            Ok(false) => {
-                return Ok(applied_some);
-            }
-            Err(e) => return Err(e),
         }
     }
-}
-
-fn apply_simple_strategy_once(
-    strategy: &StrategyFn,
-    mut state: &mut GameState,
-    mut candidates: &mut SetOfMoveCandidates,
-) -> Result<bool, bool> {
-    let applied = strategy(&mut state, &candidates);
-    if applied.is_empty() {
-        return Ok(false);
-    }
-
-    eliminate_many(&state, &mut candidates, applied.into_iter());
-    debug!(
-        "  - Candidates left after applying strategy: {}.",
-        candidates.total_len()
-    );
-
-    // If an invalid move was made here or the board isn't solvable, leave this branch.
-    if !state.validate(true) {
-        debug!("  ! Branch is invalid.");
-        return Err(false);
-    }
-
-    Ok(true)
-}
-
-fn eliminate(state: &GameState, candidates: &mut SetOfMoveCandidates, placement: &Placement) {
-    let peers = state.peer_indexes_by_index(placement.index, false, CollectType::All);
-    for peer_index in peers {
-        candidates.forget_candidate(&Placement::new(placement.value, peer_index));
-    }
-}
-
-fn eliminate_many(
-    state: &GameState,
-    candidates: &mut SetOfMoveCandidates,
-    applied: impl Iterator<Item = Placement>,
-) {
-    for placement in applied {
-        eliminate(state, candidates, &placement);
-    }
-}
-
-fn is_solvable(state: &GameState, candidates: &SetOfMoveCandidates) -> bool {
-    state.empty_cells.len() == candidates.len()
+    solved
 }
 
 fn collect_valid_symbols(game: &GameState) -> HashSet<Value> {
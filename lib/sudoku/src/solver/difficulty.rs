@@ -0,0 +1,241 @@
+use crate::solver::{explain, SolveLog, Technique};
+use crate::GameState;
+use std::fmt::{Display, Formatter};
+
+/// The rating assigned to games that cannot be solved without guessing.
+const GUESSING_RATING: f32 = 7.0;
+
+/// A coarse classification of a game's difficulty.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Tier {
+    /// Solvable with hidden singles alone, given any odd/even shading.
+    Easy,
+    /// Requires lone singles or the rules of variants such as cages, thermometers or arrows.
+    Medium,
+    /// Requires innies and outies, locked candidates, naked pairs, X-Wings or hidden pairs.
+    Hard,
+    /// Requires triples, Swordfish or XY-Wings.
+    Expert,
+    /// Cannot be solved without guessing.
+    Diabolical,
+}
+
+/// The difficulty of a game, determined by the hardest technique its logical solve requires.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Difficulty {
+    /// The numeric score on a scale modelled after Sudoku Explainer (SE) ratings.
+    pub score: f32,
+    pub tier: Tier,
+    /// The hardest technique required, if any.
+    pub hardest: Option<Technique>,
+    /// The number of steps taken to solve the game, including guesses.
+    pub steps: usize,
+    /// The number of guesses taken to solve the game.
+    pub guesses: usize,
+}
+
+/// Rates the difficulty of a game.
+pub fn rate(game: &GameState) -> Difficulty {
+    Difficulty::from(&explain(game))
+}
+
+impl Difficulty {
+    pub fn requires_guessing(&self) -> bool {
+        self.guesses > 0
+    }
+}
+
+impl Tier {
+    /// Determines the tier of the specified score.
+    pub fn from_score(score: f32) -> Tier {
+        if score < 2.0 {
+            Tier::Easy
+        } else if score < 2.5 {
+            Tier::Medium
+        } else if score < 3.5 {
+            Tier::Hard
+        } else if score < GUESSING_RATING {
+            Tier::Expert
+        } else {
+            Tier::Diabolical
+        }
+    }
+
+    /// Returns the techniques that games of this tier may require.
+    pub fn techniques(&self) -> Vec<Technique> {
+        Technique::ALL
+            .into_iter()
+            .filter(|technique| Tier::from_score(technique.rating()) <= *self)
+            .collect()
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Tier::Easy => "Easy",
+            Tier::Medium => "Medium",
+            Tier::Hard => "Hard",
+            Tier::Expert => "Expert",
+            Tier::Diabolical => "Diabolical",
+        }
+    }
+}
+
+impl From<&SolveLog> for Difficulty {
+    fn from(log: &SolveLog) -> Self {
+        let hardest = log.techniques().into_iter().max();
+        let guesses = log.guesses();
+
+        let mut score = hardest.map_or(0.0, |technique| technique.rating());
+        if guesses > 0 {
+            score = score.max(GUESSING_RATING);
+        }
+
+        Difficulty {
+            score,
+            tier: Tier::from_score(score),
+            hardest,
+            steps: log.steps.len(),
+            guesses,
+        }
+    }
+}
+
+impl Display for Tier {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl Display for Difficulty {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} ({:.1}), {} steps", self.tier, self.score, self.steps)?;
+        if self.requires_guessing() {
+            write!(f, ", {} guesses", self.guesses)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+    use crate::solver::{solve, solve_with, LogicalSolution, SolveStep};
+    use crate::{Constraint, Game, IndexBitSet, ValueBitSet};
+
+    // Puzzles from Peter Norvig's "top95" collection.
+    const HARD: [&str; 3] = [
+        "4.....8.5.3..........7......2.....6.....8.4......1.......6.3.7.5..2.....1.4......",
+        "52...6.........7.13...........4..8..6......5...........418.........3..2...87.....",
+        "6.....8.3.4.7.................5.4.7.3..2.....1.6.......2.....5.....8.6......1....",
+    ];
+
+    #[test]
+    fn rates_example() {
+        let difficulty = rate(&GameState::new(Game::new_example()));
+
+        assert!(difficulty.tier <= Tier::Medium);
+        assert!(!difficulty.requires_guessing());
+        assert_eq!(difficulty.steps, 51);
+    }
+
+    #[test]
+    fn rates_variants() {
+        let nonomino = rate(&GameState::new(Game::new_example_nonomino()));
+        let hyper = rate(&GameState::new(Game::new_example_hypersudoku()));
+
+        assert!(nonomino.tier < Tier::Diabolical);
+        assert!(hyper.tier < Tier::Diabolical);
+    }
+
+    #[test]
+    fn tiers_allow_techniques() {
+        assert_eq!(
+            Tier::Easy.techniques(),
+            vec![Technique::OddEven, Technique::HiddenSingle]
+        );
+        assert_eq!(Tier::Diabolical.techniques(), Technique::ALL.to_vec());
+    }
+
+    #[test]
+    fn rates_empty_board_as_diabolical() {
+        let difficulty = rate(&GameState::new(Game::new_empty()));
+
+        assert_eq!(difficulty.tier, Tier::Diabolical);
+        assert!(difficulty.requires_guessing());
+    }
+
+    #[test]
+    fn deductions_agree_with_solution() {
+        for puzzle in HARD {
+            let game = GameState::new(Game::new(puzzle));
+            let log = explain(&game);
+            assert!(log.is_solved());
+
+            let solution = log.final_state.state.values();
+            for step in log.steps.iter() {
+                let SolveStep::Deduction(deduction) = step else {
+                    continue;
+                };
+
+                for placement in deduction.placements.iter() {
+                    assert_eq!(solution[placement.index as usize], Some(placement.value));
+                }
+
+                for elimination in deduction.eliminations.iter() {
+                    assert_ne!(
+                        solution[elimination.index as usize],
+                        Some(elimination.value)
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn rates_custom_constraints_by_the_techniques_required() {
+        /// Rules out a digit in a cell.
+        struct Forbid(u8, Value);
+
+        impl Constraint for Forbid {
+            fn cells(&self) -> IndexBitSet {
+                IndexBitSet::default().with_value(self.0)
+            }
+
+            fn check(&self, values: &[ValueOption]) -> bool {
+                values[self.0 as usize] != Some(self.1)
+            }
+
+            fn prune(&self, candidates: &mut [ValueBitSet]) {
+                candidates[self.0 as usize].remove(self.1);
+            }
+        }
+
+        // Rule out a wrong candidate where the singles get stuck.
+        let state = GameState::new(Game::new(HARD[0]));
+        let solution = solve(&state);
+        let singles = Technique::up_to(Technique::LoneSingle);
+        let LogicalSolution::Stuck { candidates, .. } = solve_with(&state, singles) else {
+            panic!("The puzzle requires more than singles");
+        };
+        let (&index, options) = candidates.iter().next().unwrap();
+        let wrong = options
+            .iter()
+            .find(|&value| solution.state.values()[index as usize] != Some(value))
+            .unwrap();
+
+        let mut game = Game::new(HARD[0]);
+        game.add_constraint(Forbid(index, wrong));
+        let state = GameState::new(game);
+        let log = explain(&state);
+        assert!(log.techniques().contains(&Technique::Custom));
+
+        // The score is that of the hardest technique the logical solve cannot do without.
+        let difficulty = Difficulty::from(&log);
+        let hardest = difficulty.hardest.unwrap();
+        assert_eq!(difficulty.score, hardest.rating());
+        let required = Technique::up_to(hardest);
+        assert!(solve_with(&state, required).is_solved());
+        assert!(!solve_with(&state, &required[..required.len() - 1]).is_solved());
+    }
+}
@@ -0,0 +1,221 @@
+use crate::game::{IndexBitSet, ValueBitSet};
+use crate::prelude::*;
+use crate::solver::candidates::find_move_candidates;
+use crate::solver::logic::{apply_deduction, next_deduction, place};
+use crate::solver::search::find_solutions;
+use crate::solver::{Deduction, Technique};
+use crate::{Game, GameState, Placement};
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+use std::rc::Rc;
+
+/// A guess made when none of the techniques applied.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Guess {
+    /// The value placed by the guess.
+    pub placement: Placement,
+    /// The candidates the cell had when the guess was made.
+    pub candidates: ValueBitSet,
+}
+
+/// A single entry of a [`SolveLog`].
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum SolveStep {
+    Deduction(Deduction),
+    Guess(Guess),
+}
+
+/// A step-by-step record of how a game is solved.
+pub struct SolveLog {
+    game: Rc<Game>,
+    /// The steps in the order in which they were taken.
+    pub steps: Vec<SolveStep>,
+    /// The state after the last step.
+    pub final_state: GameState,
+}
+
+/// Solves the game by logic alone, recording every step taken along the way.
+///
+/// Whenever no technique applies, the cell with the fewest candidates is filled with the value
+/// of the (first) solution of the game and recorded as a [`Guess`]. If the game has no solution,
+/// the log ends at the point where no further progress could be made.
+pub fn explain(game: &GameState) -> SolveLog {
+    let solution = find_solutions(game, 1).pop();
+
+    let valid_symbols: HashSet<Value> = game.valid_symbols().iter().copied().collect();
+    let mut candidates = find_move_candidates(game, &valid_symbols);
+    let mut state = game.clone();
+    let mut steps = Vec::new();
+
+    while !state.empty_cells.is_empty() {
+        if let Some(deduction) = next_deduction(&state, &candidates, &Technique::ALL) {
+            apply_deduction(&mut state, &mut candidates, &deduction);
+            steps.push(SolveStep::Deduction(deduction));
+            continue;
+        }
+
+        let Some(solution) = &solution else {
+            break;
+        };
+
+        // Guess on the cell with the fewest candidates, preferring the first one.
+        let Some(index) = candidates
+            .iter()
+            .min_by_key(|c| (c.moves.len(), c.index))
+            .map(|c| c.index)
+        else {
+            break;
+        };
+
+        let value = solution.values()[index as usize].unwrap();
+        let guess = Guess {
+            placement: Placement::new(value, index),
+            candidates: candidates.values(index),
+        };
+
+        place(&mut state, &mut candidates, &guess.placement);
+        steps.push(SolveStep::Guess(guess));
+    }
+
+    SolveLog {
+        game: game.game.clone(),
+        steps,
+        final_state: state,
+    }
+}
+
+impl SolveStep {
+    /// Returns the technique used, or `None` for a guess.
+    pub fn technique(&self) -> Option<Technique> {
+        match self {
+            SolveStep::Deduction(deduction) => Some(deduction.technique),
+            SolveStep::Guess(_) => None,
+        }
+    }
+
+    /// Returns the cells changed by this step.
+    pub fn cells(&self) -> IndexBitSet {
+        match self {
+            SolveStep::Deduction(deduction) => deduction.cells(),
+            SolveStep::Guess(guess) => IndexBitSet::default().with_value(guess.placement.index),
+        }
+    }
+}
+
+impl SolveLog {
+    /// Determines whether the steps led to a valid solution.
+    pub fn is_solved(&self) -> bool {
+        self.final_state.validate(false)
+    }
+
+    /// Returns the number of guesses that were required.
+    pub fn guesses(&self) -> usize {
+        self.steps
+            .iter()
+            .filter(|step| matches!(step, SolveStep::Guess(_)))
+            .count()
+    }
+
+    /// Returns the distinct techniques used, in ascending order.
+    pub fn techniques(&self) -> Vec<Technique> {
+        let mut techniques: Vec<_> = self.steps.iter().filter_map(|s| s.technique()).collect();
+        techniques.sort_unstable();
+        techniques.dedup();
+        techniques
+    }
+
+    /// Names the cells changed by a step in `r?c?` notation.
+    pub fn cell_names(&self, step: &SolveStep) -> Vec<String> {
+        step.cells()
+            .iter()
+            .map(|index| self.game.cell_name(index))
+            .collect()
+    }
+}
+
+impl Display for SolveLog {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (number, step) in self.steps.iter().enumerate() {
+            match step {
+                SolveStep::Deduction(deduction) => writeln!(
+                    f,
+                    "{:>3}. {}: {} ({})",
+                    number + 1,
+                    deduction.technique,
+                    deduction.describe_effect(&self.game),
+                    deduction.reason
+                )?,
+                SolveStep::Guess(guess) => {
+                    let options: Vec<_> = guess.candidates.iter().map(|v| v.to_string()).collect();
+                    writeln!(
+                        f,
+                        "{:>3}. Guess: {} = {} (candidates {})",
+                        number + 1,
+                        self.game.cell_name(guess.placement.index),
+                        guess.placement.value,
+                        options.join(", ")
+                    )?
+                }
+            }
+        }
+
+        if self.is_solved() {
+            writeln!(f, "Solved in {} steps.", self.steps.len())
+        } else {
+            writeln!(f, "Stuck after {} steps.", self.steps.len())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn explains_example_without_guessing() {
+        let game = GameState::new(Game::new_example());
+        let log = explain(&game);
+
+        assert!(log.is_solved());
+        assert_eq!(log.guesses(), 0);
+        assert_eq!(log.steps.len(), game.empty_cells.len());
+    }
+
+    #[test]
+    fn explains_nonomino() {
+        let game = GameState::new(Game::new_example_nonomino());
+        let log = explain(&game);
+
+        assert!(log.is_solved());
+    }
+
+    #[test]
+    fn explains_hypersudoku() {
+        let game = GameState::new(Game::new_example_hypersudoku());
+        let log = explain(&game);
+
+        assert!(log.is_solved());
+    }
+
+    #[test]
+    fn guesses_on_empty_board() {
+        let game = GameState::new(Game::new_empty());
+        let log = explain(&game);
+
+        assert!(log.is_solved());
+        assert!(log.guesses() > 0);
+    }
+
+    #[test]
+    fn renders_cells_in_rc_notation() {
+        let game = GameState::new(Game::new_example());
+        let log = explain(&game);
+        let text = log.to_string();
+
+        let first = &log.steps[0];
+        for name in log.cell_names(first) {
+            assert!(text.lines().next().unwrap().contains(&name));
+        }
+        assert!(text.ends_with("Solved in 51 steps.\n"));
+    }
+}
@@ -0,0 +1,63 @@
+use crate::game::non_consecutive::consecutive_neighbors;
+use crate::game::{CollectType, Placement};
+use crate::solver::candidates::SetOfMoveCandidates;
+use crate::solver::steps::strategy;
+use crate::solver::{Deduction, Technique};
+use crate::GameState;
+
+/// Finds the next step using the first of the specified techniques that applies.
+pub(super) fn next_deduction(
+    state: &GameState,
+    candidates: &SetOfMoveCandidates,
+    techniques: &[Technique],
+) -> Option<Deduction> {
+    techniques
+        .iter()
+        .find_map(|&technique| strategy(technique)(state, candidates))
+}
+
+/// Applies the placements and eliminations of a step to the state and its candidates.
+pub(super) fn apply_deduction(
+    state: &mut GameState,
+    candidates: &mut SetOfMoveCandidates,
+    deduction: &Deduction,
+) {
+    for placement in deduction.placements.iter() {
+        place(state, candidates, placement);
+    }
+
+    for elimination in deduction.eliminations.iter() {
+        candidates.forget_candidate(elimination);
+    }
+}
+
+/// Places a value and removes it from the candidates of all peers.
+pub(super) fn place(
+    state: &mut GameState,
+    candidates: &mut SetOfMoveCandidates,
+    placement: &Placement,
+) {
+    state.apply_move(placement);
+    candidates.forget_index(placement.index);
+    eliminate(state, candidates, placement);
+}
+
+pub(super) fn eliminate(
+    state: &GameState,
+    candidates: &mut SetOfMoveCandidates,
+    placement: &Placement,
+) {
+    let peers = state.peer_indexes_by_index(placement.index, false, CollectType::All);
+    for peer_index in peers {
+        candidates.forget_candidate(&Placement::new(placement.value, peer_index));
+    }
+
+    for neighbor in consecutive_neighbors(&state.game, placement) {
+        candidates.forget_candidate(&neighbor);
+    }
+}
+
+/// Determines whether every open cell still has at least one candidate.
+pub(super) fn is_solvable(state: &GameState, candidates: &SetOfMoveCandidates) -> bool {
+    state.empty_cells.len() == candidates.len()
+}
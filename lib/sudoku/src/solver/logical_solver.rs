@@ -0,0 +1,273 @@
+use crate::game::ValueBitSet;
+use crate::prelude::*;
+use crate::solver::candidates::find_move_candidates;
+use crate::solver::logic::{apply_deduction, next_deduction};
+use crate::solver::Technique;
+use crate::GameState;
+use log::debug;
+use std::collections::{BTreeMap, HashSet};
+
+/// The outcome of a solve restricted to a set of techniques.
+pub enum LogicalSolution {
+    /// The techniques were sufficient to solve the game.
+    Solved(GameState),
+    /// None of the techniques made further progress.
+    Stuck {
+        /// The state at the point where the solver got stuck.
+        state: GameState,
+        /// The remaining candidates of every open cell. A cell without any candidates indicates
+        /// that the game has no solution.
+        candidates: BTreeMap<Index, ValueBitSet>,
+    },
+}
+
+/// Solves the game using only the specified techniques, never branching.
+///
+/// ## Example
+///
+/// ```
+/// # use sudoku::{Game, GameState};
+/// # use sudoku::solver::{solve_with, Technique};
+/// let game = GameState::new(Game::new_example());
+/// let singles_only = Technique::up_to(Technique::LoneSingle);
+/// assert!(solve_with(&game, singles_only).is_solved());
+/// ```
+pub fn solve_with(game: &GameState, techniques: &[Technique]) -> LogicalSolution {
+    let valid_symbols: HashSet<Value> = game.valid_symbols().iter().copied().collect();
+    let mut candidates = find_move_candidates(game, &valid_symbols);
+    let mut state = game.clone();
+
+    while state.validate(true) {
+        let Some(deduction) = next_deduction(&state, &candidates, techniques) else {
+            break;
+        };
+
+        debug!("  - {}: {}", deduction.technique, deduction.reason);
+        apply_deduction(&mut state, &mut candidates, &deduction);
+    }
+
+    if state.validate(false) {
+        return LogicalSolution::Solved(state);
+    }
+
+    let candidates = state
+        .empty_cells
+        .iter()
+        .map(|index| (index, candidates.values(index)))
+        .collect();
+    LogicalSolution::Stuck { state, candidates }
+}
+
+impl LogicalSolution {
+    pub fn is_solved(&self) -> bool {
+        matches!(self, LogicalSolution::Solved(_))
+    }
+
+    /// Returns the solved state, or the state at the point where the solver got stuck.
+    pub fn state(&self) -> &GameState {
+        match self {
+            LogicalSolution::Solved(state) => state,
+            LogicalSolution::Stuck { state, .. } => state,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::{count_solutions, solve};
+    use crate::{Cage, Game};
+
+    // Requires locked candidates.
+    const POINTING: &str =
+        "4.....8.5.3..........7......2.....6.....8.4......1.......6.3.7.5..2.....1.4......";
+
+    #[test]
+    fn solves_with_singles_only() {
+        let game = GameState::new(Game::new_example());
+        let solution = solve_with(&game, Technique::up_to(Technique::LoneSingle));
+
+        assert!(solution.is_solved());
+        assert!(solution.state().validate(false));
+    }
+
+    #[test]
+    fn reports_stuck_state() {
+        let game = GameState::new(Game::new(POINTING));
+        let LogicalSolution::Stuck { state, candidates } =
+            solve_with(&game, Technique::up_to(Technique::LoneSingle))
+        else {
+            panic!("Expected the solver to get stuck");
+        };
+
+        assert!(!state.empty_cells.is_empty());
+        assert_eq!(candidates.len(), state.empty_cells.len());
+        assert!(candidates.values().all(|values| values.len() > 1));
+    }
+
+    #[test]
+    fn solves_with_more_techniques() {
+        let game = GameState::new(Game::new(POINTING));
+        let solution = solve_with(&game, Technique::up_to(Technique::Pointing));
+
+        assert!(solution.is_solved());
+    }
+
+    #[test]
+    fn reports_contradiction() {
+        let game = GameState::new(Game::new(
+            "11...............................................................................",
+        ));
+        let solution = solve_with(&game, &Technique::ALL);
+
+        assert!(!solution.is_solved());
+    }
+
+    #[test]
+    fn solves_killer_with_cage_techniques() {
+        let game = GameState::new(Game::new_example_killer());
+        assert!(!solve_with(&game, Technique::up_to(Technique::LoneSingle)).is_solved());
+
+        let solution = solve_with(&game, Technique::up_to(Technique::InnieOutie));
+        assert!(solution.is_solved());
+        assert!(solution.state().validate(false));
+    }
+
+    #[test]
+    fn solves_thermo_with_thermometers() {
+        let game = GameState::new(Game::new_example_thermo());
+        let solution = solve_with(&game, Technique::up_to(Technique::LoneSingle));
+
+        assert!(solution.is_solved());
+        assert!(solution.state().validate(false));
+    }
+
+    #[test]
+    fn solves_arrow_with_arrows() {
+        let game = GameState::new(Game::new_example_arrow());
+        assert!(!solve_with(&game, Technique::up_to(Technique::Thermometer)).is_solved());
+
+        let solution = solve_with(&game, Technique::up_to(Technique::LoneSingle));
+        assert!(solution.is_solved());
+        assert!(solution.state().validate(false));
+    }
+
+    #[test]
+    fn solves_kropki_and_xv_with_markers() {
+        for game in [Game::new_example_kropki(), Game::new_example_xv()] {
+            let game = GameState::new(game);
+            assert!(!solve_with(&game, Technique::up_to(Technique::Arrow)).is_solved());
+
+            let solution = solve_with(&game, &Technique::ALL);
+            assert!(solution.is_solved());
+            assert!(solution.state().validate(false));
+        }
+    }
+
+    #[test]
+    fn solves_with_chess_move_peers() {
+        for game in [
+            Game::new_example_anti_knight(),
+            Game::new_example_anti_king(),
+        ] {
+            let solution = solve_with(&GameState::new(game), &Technique::ALL);
+
+            assert!(solution.is_solved());
+            assert!(solution.state().validate(false));
+        }
+    }
+
+    #[test]
+    fn solves_non_consecutive() {
+        let game = GameState::new(Game::new_example_non_consecutive());
+        let solution = solve_with(&game, &Technique::ALL);
+
+        assert!(solution.is_solved());
+        assert!(solution.state().validate(false));
+    }
+
+    #[test]
+    fn solves_odd_even_and_greater_than() {
+        for game in [
+            Game::new_example_odd_even(),
+            Game::new_example_greater_than(),
+        ] {
+            let solution = solve_with(&GameState::new(game), &Technique::ALL);
+
+            assert!(solution.is_solved());
+            assert!(solution.state().validate(false));
+        }
+    }
+
+    #[test]
+    fn solves_sandwich_and_x_sums() {
+        for game in [Game::new_example_sandwich(), Game::new_example_x_sums()] {
+            let game = GameState::new(game);
+            assert!(!solve_with(&game, Technique::up_to(Technique::LoneSingle)).is_solved());
+
+            let solution = solve_with(&game, &Technique::ALL);
+            assert!(solution.is_solved());
+            assert!(solution.state().validate(false));
+        }
+    }
+
+    #[test]
+    fn solves_lines() {
+        let game = GameState::new(Game::new_example_lines());
+        assert_eq!(count_solutions(&game, 2), 1);
+
+        let solution = solve_with(&game, &Technique::ALL);
+        assert!(solution.is_solved());
+        assert!(solution.state().validate(false));
+        assert_eq!(solve(&game).state.values(), solution.state().state.values());
+    }
+
+    #[test]
+    fn solves_little_killer() {
+        let game = GameState::new(Game::new_example_little_killer());
+        assert_eq!(count_solutions(&game, 2), 1);
+        assert!(!solve_with(&game, Technique::up_to(Technique::LoneSingle)).is_solved());
+
+        let solution = solve_with(&game, &Technique::ALL);
+        assert!(solution.is_solved());
+        assert!(solution.state().validate(false));
+    }
+
+    #[test]
+    fn solves_skyscraper() {
+        let game = GameState::new(Game::new_example_skyscraper());
+        assert_eq!(count_solutions(&game, 2), 1);
+
+        let solution = solve_with(&game, &Technique::ALL);
+        assert!(solution.is_solved());
+        assert_eq!(solve(&game).state.values(), solution.state().state.values());
+    }
+
+    #[test]
+    fn skips_the_rule_of_45_for_overlapping_cages() {
+        // The first row would appear to sum to 37 outside the cages, rather than 38 or 39.
+        let mut game = Game::new_empty();
+        game.add_constraints([Cage::new(indexes!(0, 1), 3), Cage::new(indexes!(1, 2), 5)]);
+        let state = GameState::new(game);
+
+        let LogicalSolution::Stuck { candidates, .. } =
+            solve_with(&state, &[Technique::InnieOutie])
+        else {
+            panic!("The empty game cannot be solved");
+        };
+        assert_eq!(candidates[&3].len(), 9);
+    }
+
+    #[test]
+    fn ignores_the_placeholder_groups_of_chaos_games() {
+        // Any deduction must hold for every layout, including the one of the original puzzle.
+        let nonomino = GameState::new(Game::new_example_nonomino());
+        let solution = solve(&nonomino);
+        let chaos = GameState::new(Game::new_chaos(*nonomino.state.values()));
+
+        let state = solve_with(&chaos, &Technique::ALL).state().clone();
+        for (value, expected) in state.state.values().iter().zip(solution.state.values()) {
+            assert!(value.is_none() || value == expected);
+        }
+    }
+}
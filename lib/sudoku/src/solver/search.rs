@@ -0,0 +1,314 @@
+use crate::game::constraint::Constraint;
+use crate::game::ValueBitSet;
+use crate::prelude::*;
+use crate::random::Random;
+use crate::{GameState, House, State};
+use std::cell::Cell;
+use std::rc::Rc;
+
+/// Counts the solutions of the given state, stopping once `limit` solutions were found.
+///
+/// A game is uniquely solvable if `count_solutions(game, 2)` returns `1`.
+pub fn count_solutions(state: &GameState, limit: usize) -> usize {
+    find_solutions(state, limit).len()
+}
+
+/// Exhaustively searches for solutions of the given state, stopping after `limit` solutions.
+///
+/// Unlike [`solve`](crate::solver::solve), this search does not apply any techniques; it
+/// simply fills the cell with the fewest options first and backtracks on dead ends. It is used
+/// wherever a definite answer is required, e.g. to resolve guesses when explaining a solve.
+pub(crate) fn find_solutions(state: &GameState, limit: usize) -> Vec<State> {
+    if limit == 0 || !state.validate(true) {
+        return Vec::new();
+    }
+
+    let mut solutions = Vec::new();
+    let search = Search::new(state);
+    let mut values = *state.state.values();
+    search.run(&mut values, limit, &mut solutions, None);
+    solutions.into_iter().map(to_state).collect()
+}
+
+/// Determines whether the state has a solution, giving up after visiting `max_nodes` nodes
+/// of the search tree. Returns `None` if the search was inconclusive.
+pub(crate) fn has_solution_within(state: &GameState, max_nodes: usize) -> Option<bool> {
+    if !state.validate(true) {
+        return Some(false);
+    }
+
+    Search::new(state).solvable_within(*state.state.values(), max_nodes)
+}
+
+/// Finds a random solution of the given state, trying the options of each cell in random order.
+pub(crate) fn find_random_solution(state: &GameState, random: &mut Random) -> Option<State> {
+    if !state.validate(true) {
+        return None;
+    }
+
+    let mut solutions = Vec::new();
+    let search = Search::new(state);
+    let mut values = *state.state.values();
+    search.run(&mut values, 1, &mut solutions, Some(random));
+    solutions.pop().map(to_state)
+}
+
+fn to_state(values: Vec<ValueOption>) -> State {
+    State::new(values.try_into().expect("A standard game has 81 cells"))
+}
+
+pub(crate) struct Search {
+    /// The cells of each house. Cells outside of all houses are not part of the puzzle.
+    houses: Vec<Vec<usize>>,
+    /// The houses of each cell.
+    houses_of: Vec<Vec<usize>>,
+    /// The cells outside the houses of each cell that must not repeat its digit, such as the
+    /// cells a knight's move away.
+    peers: Vec<Vec<usize>>,
+    symbols: ValueBitSet,
+    /// The number of search nodes that may still be visited.
+    budget: Cell<usize>,
+    /// The constraints beyond the houses, e.g. killer cages.
+    constraints: Vec<Rc<dyn Constraint>>,
+}
+
+/// The alternatives to branch on: either all options of a cell or all cells of a house
+/// that can still take a value.
+enum Branch {
+    /// There are no open cells left.
+    Solved,
+    Cell(usize, ValueBitSet),
+    House(Vec<usize>, Value),
+}
+
+impl Search {
+    pub(crate) fn new(state: &GameState) -> Search {
+        let houses = state.game.houses();
+        let mut search = Search::with_houses(
+            houses
+                .iter()
+                .map(|house| house.cells.iter().map(|index| index as usize).collect())
+                .collect(),
+            state.state.values().len(),
+        );
+        search.peers = (0..state.state.values().len())
+            .map(|index| {
+                let mut peers = state.game.peers(index as Index);
+                for house in houses.iter() {
+                    peers = peers.without_all(&house.cells);
+                }
+                peers.iter().map(|peer| peer as usize).collect()
+            })
+            .collect();
+        search.symbols = ValueBitSet::from(state.valid_symbols().as_slice());
+        search.constraints = state
+            .game
+            .constraints()
+            .iter()
+            .filter(|constraint| {
+                constraint
+                    .as_ref()
+                    .as_any()
+                    .downcast_ref::<House>()
+                    .is_none()
+            })
+            .cloned()
+            .collect();
+        search
+    }
+
+    /// Creates a search over a board of `cells` cells with the specified houses of nine
+    /// cells each, such as the board of a multi-grid puzzle.
+    pub(crate) fn with_houses(houses: Vec<Vec<usize>>, cells: usize) -> Search {
+        let mut houses_of = vec![Vec::new(); cells];
+        for (id, house) in houses.iter().enumerate() {
+            for &index in house.iter() {
+                houses_of[index].push(id);
+            }
+        }
+
+        Search {
+            houses,
+            houses_of,
+            peers: vec![Vec::new(); cells],
+            symbols: ValueBitSet::from(&[1u8, 2, 3, 4, 5, 6, 7, 8, 9][..]),
+            budget: Cell::new(usize::MAX),
+            constraints: Vec::new(),
+        }
+    }
+
+    /// Adds a house whose cells must hold distinct values, such as a deduced region.
+    pub(crate) fn add_house(&mut self, cells: Vec<usize>) {
+        for &index in cells.iter() {
+            self.houses_of[index].push(self.houses.len());
+        }
+        self.houses.push(cells);
+    }
+
+    /// Determines whether the values can be completed to a solution, giving up after visiting
+    /// `max_nodes` nodes of the search tree. Returns `None` if the search was inconclusive.
+    pub(crate) fn solvable_within(
+        &self,
+        mut values: [ValueOption; 81],
+        max_nodes: usize,
+    ) -> Option<bool> {
+        let mut solutions = Vec::new();
+        self.budget.set(max_nodes);
+        self.run(&mut values, 1, &mut solutions, None);
+        let exhausted = self.budget.replace(usize::MAX) == 0;
+
+        if !solutions.is_empty() {
+            Some(true)
+        } else if exhausted {
+            None
+        } else {
+            Some(false)
+        }
+    }
+
+    pub(crate) fn run(
+        &self,
+        values: &mut [ValueOption],
+        limit: usize,
+        solutions: &mut Vec<Vec<ValueOption>>,
+        mut random: Option<&mut Random>,
+    ) {
+        if self.budget.get() == 0 {
+            return;
+        }
+        self.budget.set(self.budget.get() - 1);
+
+        let Some(branch) = self.select_branch(values) else {
+            return;
+        };
+
+        // The alternatives as (cell, value) pairs.
+        let mut alternatives: Vec<(usize, Value)> = match branch {
+            Branch::Solved => {
+                solutions.push(values.to_vec());
+                return;
+            }
+            Branch::Cell(index, options) => options.iter().map(|value| (index, value)).collect(),
+            Branch::House(cells, value) => cells.into_iter().map(|index| (index, value)).collect(),
+        };
+
+        if let Some(random) = random.as_deref_mut() {
+            random.shuffle(&mut alternatives);
+        }
+
+        for (index, value) in alternatives {
+            values[index] = Some(value);
+            self.run(values, limit, solutions, random.as_deref_mut());
+            values[index] = None;
+            if solutions.len() >= limit {
+                break;
+            }
+        }
+    }
+
+    /// Selects the most constrained cell or house to branch on. Returns `None` on a dead end.
+    fn select_branch(&self, values: &[ValueOption]) -> Option<Branch> {
+        let mut used = vec![ValueBitSet::default(); self.houses.len()];
+        for (house, cells) in self.houses.iter().enumerate() {
+            for value in cells.iter().filter_map(|&index| values[index]) {
+                used[house].insert(value);
+            }
+        }
+
+        if !self.constraints.iter().all(|c| c.check(values)) {
+            return None;
+        }
+
+        // The options of every cell; filled cells are restricted to their value.
+        let mut options = vec![ValueBitSet::default(); values.len()];
+        for (index, value) in values.iter().enumerate() {
+            options[index] = match value {
+                Some(value) => ValueBitSet::default().with_value(*value),
+                None => {
+                    let mut options = self.houses_of[index]
+                        .iter()
+                        .fold(self.symbols, |options, &house| {
+                            options.without_all(&used[house])
+                        });
+                    for value in self.peers[index].iter().filter_map(|&peer| values[peer]) {
+                        options.remove(value);
+                    }
+                    options
+                }
+            };
+        }
+        self.propagate(&mut options);
+
+        let mut best: Option<Branch> = None;
+        let mut best_len = usize::MAX;
+        for (index, value) in values.iter().enumerate() {
+            if self.houses_of[index].is_empty() {
+                continue;
+            }
+
+            if options[index].is_empty() {
+                return None;
+            }
+
+            if value.is_none() && options[index].len() < best_len {
+                best_len = options[index].len();
+                best = Some(Branch::Cell(index, options[index]));
+            }
+        }
+
+        let Some(mut best) = best else {
+            return Some(Branch::Solved);
+        };
+
+        if best_len == 1 {
+            return Some(best);
+        }
+
+        // A value missing from a house that fits into fewer cells than the best cell has options.
+        for (house, cells) in self.houses.iter().enumerate() {
+            if cells.len() != self.symbols.len() {
+                continue;
+            }
+
+            for value in self.symbols.without_all(&used[house]).iter() {
+                let positions: Vec<usize> = cells
+                    .iter()
+                    .copied()
+                    .filter(|&index| options[index].contains(value))
+                    .collect();
+
+                if positions.is_empty() {
+                    return None;
+                }
+
+                if positions.len() < best_len {
+                    best_len = positions.len();
+                    best = Branch::House(positions, value);
+                    if best_len == 1 {
+                        return Some(best);
+                    }
+                }
+            }
+        }
+
+        Some(best)
+    }
+
+    /// Prunes the options by the constraints until they no longer change.
+    fn propagate(&self, options: &mut [ValueBitSet]) {
+        if self.constraints.is_empty() {
+            return;
+        }
+
+        loop {
+            let before = options.to_vec();
+            for constraint in self.constraints.iter() {
+                constraint.prune(options);
+            }
+
+            if options == before.as_slice() {
+                return;
+            }
+        }
+    }
+}
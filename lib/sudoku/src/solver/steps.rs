@@ -1,5 +1,112 @@
+mod constraints;
+mod fish;
 mod hidden_singles;
+mod hidden_subsets;
+mod killer;
+mod locked_candidates;
 mod lone_singles;
+mod naked_subsets;
+mod xy_wing;
 
+use crate::game::{CollectType, IndexBitSet, ValueBitSet};
+use crate::prelude::*;
+use crate::solver::candidates::SetOfMoveCandidates;
+use crate::solver::{Deduction, Technique};
+use crate::{Game, GameState, Placement};
+
+pub(super) use constraints::constraint_eliminations;
+pub(super) use fish::{swordfish, x_wing};
 pub(super) use hidden_singles::hidden_singles;
+pub(super) use hidden_subsets::{hidden_pairs, hidden_triples};
+pub(super) use killer::innies_outies;
+pub(super) use locked_candidates::{claiming, pointing};
 pub(super) use lone_singles::lone_singles;
+pub(super) use naked_subsets::{naked_pairs, naked_triples};
+pub(super) use xy_wing::xy_wing;
+
+/// A strategy looks for the next step its technique allows on the given state.
+pub type Strategy = Box<dyn Fn(&GameState, &SetOfMoveCandidates) -> Option<Deduction>>;
+
+/// Returns the strategy implementing the specified technique.
+pub(super) fn strategy(technique: Technique) -> Strategy {
+    match technique {
+        Technique::HiddenSingle => Box::new(hidden_singles),
+        Technique::LoneSingle => Box::new(lone_singles),
+        Technique::InnieOutie => Box::new(innies_outies),
+        Technique::Pointing => Box::new(pointing),
+        Technique::Claiming => Box::new(claiming),
+        Technique::NakedPair => Box::new(naked_pairs),
+        Technique::XWing => Box::new(x_wing),
+        Technique::HiddenPair => Box::new(hidden_pairs),
+        Technique::NakedTriple => Box::new(naked_triples),
+        Technique::Swordfish => Box::new(swordfish),
+        Technique::HiddenTriple => Box::new(hidden_triples),
+        Technique::XYWing => Box::new(xy_wing),
+        // The remaining techniques are credited by the constraints of the game, e.g. cages.
+        technique => {
+            Box::new(move |state, candidates| constraint_eliminations(state, candidates, technique))
+        }
+    }
+}
+
+/// Returns the cells of the specified set that still have the value as a candidate.
+fn cells_with(candidates: &SetOfMoveCandidates, cells: &IndexBitSet, value: Value) -> IndexBitSet {
+    let mut matches = IndexBitSet::default();
+    for index in cells.iter() {
+        if candidates.contains(&Placement::new(value, index)) {
+            matches.insert(index);
+        }
+    }
+    matches
+}
+
+/// Returns the candidates of every cell, where filled cells are restricted to their value.
+fn candidate_grid(state: &GameState, candidates: &SetOfMoveCandidates) -> Vec<ValueBitSet> {
+    state
+        .state
+        .values()
+        .iter()
+        .enumerate()
+        .map(|(index, value)| match value {
+            Some(value) => ValueBitSet::default().with_value(*value),
+            None => candidates.values(index as Index),
+        })
+        .collect()
+}
+
+/// Returns the peers of a cell, excluding the cell itself.
+fn peers(state: &GameState, index: Index) -> IndexBitSet {
+    let mut peers = IndexBitSet::default();
+    for peer in state.peer_indexes_by_index(index, true, CollectType::All) {
+        peers.insert(peer);
+    }
+    peers
+}
+
+/// Returns all combinations of `size` items, preserving the order of the items.
+fn combinations<T: Copy>(items: &[T], size: usize) -> Vec<Vec<T>> {
+    if size == 0 {
+        return vec![Vec::new()];
+    }
+
+    let mut result = Vec::new();
+    for (i, item) in items.iter().enumerate() {
+        for mut rest in combinations(&items[i + 1..], size - 1) {
+            rest.insert(0, *item);
+            result.push(rest);
+        }
+    }
+    result
+}
+
+/// Lists cells in `r?c?` notation, e.g. `r1c2, r1c5`.
+fn cell_list(game: &Game, cells: &IndexBitSet) -> String {
+    let names: Vec<_> = cells.iter().map(|index| game.cell_name(index)).collect();
+    names.join(", ")
+}
+
+/// Lists values, e.g. `3, 8`.
+fn value_list(values: &ValueBitSet) -> String {
+    let names: Vec<_> = values.iter().map(|value| value.to_string()).collect();
+    names.join(", ")
+}
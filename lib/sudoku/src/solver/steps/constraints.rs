@@ -0,0 +1,36 @@
+use crate::solver::candidates::SetOfMoveCandidates;
+use crate::solver::steps::candidate_grid;
+use crate::solver::{Deduction, Technique};
+use crate::{GameState, Placement};
+
+/// Finds candidates that one of the game's constraints credited to the technique rules out.
+pub fn constraint_eliminations(
+    state: &GameState,
+    candidates: &SetOfMoveCandidates,
+    technique: Technique,
+) -> Option<Deduction> {
+    let grid = candidate_grid(state, candidates);
+
+    for constraint in state.game.constraints() {
+        if constraint.technique() != technique {
+            continue;
+        }
+
+        let mut pruned = grid.clone();
+        constraint.prune(&mut pruned);
+
+        let mut eliminations = Vec::new();
+        let cells = constraint.cells().with_intersection(&state.empty_cells);
+        for index in cells.iter() {
+            let removed = grid[index as usize].without_all(&pruned[index as usize]);
+            eliminations.extend(removed.iter().map(|value| Placement::new(value, index)));
+        }
+
+        if !eliminations.is_empty() {
+            let reason = constraint.describe(&state.game);
+            return Some(Deduction::elimination(technique, eliminations, reason));
+        }
+    }
+
+    None
+}
@@ -0,0 +1,102 @@
+use super::combinations;
+use crate::game::Placement;
+use crate::prelude::*;
+use crate::solver::candidates::SetOfMoveCandidates;
+use crate::solver::{Deduction, Technique};
+use crate::GameState;
+
+/// Finds a value confined to the same two columns in two rows, or vice versa.
+pub fn x_wing(state: &GameState, candidates: &SetOfMoveCandidates) -> Option<Deduction> {
+    fish(state, candidates, 2, Technique::XWing)
+}
+
+/// Finds a value confined to the same three columns in three rows, or vice versa.
+pub fn swordfish(state: &GameState, candidates: &SetOfMoveCandidates) -> Option<Deduction> {
+    fish(state, candidates, 3, Technique::Swordfish)
+}
+
+fn fish(
+    state: &GameState,
+    candidates: &SetOfMoveCandidates,
+    size: usize,
+    technique: Technique,
+) -> Option<Deduction> {
+    let (width, height) = (state.game.width, state.game.height);
+
+    for value in state.valid_symbols() {
+        for by_rows in [true, false] {
+            let (lines, positions) = if by_rows {
+                (height, width)
+            } else {
+                (width, height)
+            };
+
+            let cell = |line: usize, position: usize| {
+                if by_rows {
+                    state.xy_to_index(position, line)
+                } else {
+                    state.xy_to_index(line, position)
+                }
+            };
+
+            // For each base line, the positions that still hold the value as a candidate.
+            let covers: Vec<u32> = (0..lines)
+                .map(|line| {
+                    (0..positions)
+                        .filter(|&position| {
+                            candidates.contains(&Placement::new(*value, cell(line, position)))
+                        })
+                        .fold(0u32, |mask, position| mask | (1 << position))
+                })
+                .collect();
+
+            let base: Vec<usize> = (0..lines)
+                .filter(|&line| (2..=size as u32).contains(&covers[line].count_ones()))
+                .collect();
+
+            for subset in combinations(&base, size) {
+                let cover = subset.iter().fold(0u32, |mask, &line| mask | covers[line]);
+                if cover.count_ones() as usize != size {
+                    continue;
+                }
+
+                let mut eliminations = Vec::new();
+                for line in (0..lines).filter(|line| !subset.contains(line)) {
+                    for position in (0..positions).filter(|p| cover & (1 << p) != 0) {
+                        let candidate = Placement::new(*value, cell(line, position));
+                        if candidates.contains(&candidate) {
+                            eliminations.push(candidate);
+                        }
+                    }
+                }
+
+                if eliminations.is_empty() {
+                    continue;
+                }
+
+                let (base_name, cover_name) = if by_rows {
+                    ("rows", "columns")
+                } else {
+                    ("columns", "rows")
+                };
+                let reason = format!(
+                    "in {} {}, {} is confined to {} {}",
+                    base_name,
+                    numbers(subset.iter().copied()),
+                    value,
+                    cover_name,
+                    numbers((0..positions).filter(|p| cover & (1 << p) != 0))
+                );
+                return Some(Deduction::elimination(technique, eliminations, reason));
+            }
+        }
+    }
+
+    None
+}
+
+/// Lists zero-based coordinates as one-based numbers, e.g. `1, 5`.
+fn numbers(coordinates: impl Iterator<Item = Coordinate>) -> String {
+    let names: Vec<_> = coordinates.map(|c| (c + 1).to_string()).collect();
+    names.join(", ")
+}
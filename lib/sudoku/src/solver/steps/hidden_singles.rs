@@ -1,25 +1,33 @@
 use crate::solver::candidates::SetOfMoveCandidates;
+use crate::solver::{Deduction, Technique};
 use crate::{GameState, Placement};
-use std::collections::HashSet;
 
-pub fn hidden_singles(state: &mut GameState, candidates: &SetOfMoveCandidates) -> Vec<Placement> {
-    let mut applied = Vec::new();
+/// Finds a value that can only go into a single cell of a house.
+pub fn hidden_singles(state: &GameState, candidates: &SetOfMoveCandidates) -> Option<Deduction> {
+    for house in state.game.houses() {
+        for value in state.valid_symbols() {
+            let mut cells = house
+                .cells
+                .iter()
+                .filter(|&index| candidates.contains(&Placement::new(*value, index)));
 
-    for candidate in candidates.iter() {
-        let value_candidates: HashSet<_> = candidate.moves.iter().map(|p| p.value).collect();
-        let peer_values: HashSet<_> = state
-            .peers_by_index(candidate.index, true)
-            .iter()
-            .map(|p| p.value)
-            .collect();
+            let (Some(index), None) = (cells.next(), cells.next()) else {
+                continue;
+            };
 
-        let mut difference: HashSet<_> = value_candidates.difference(&peer_values).collect();
-
-        if difference.len() == 1 {
-            let value = *difference.drain().next().unwrap();
-            applied.push(Placement::new(value, candidate.index));
+            let reason = format!(
+                "{} can only go into {} within {}",
+                value,
+                state.game.cell_name(index),
+                house
+            );
+            return Some(Deduction::placement(
+                Technique::HiddenSingle,
+                Placement::new(*value, index),
+                reason,
+            ));
         }
     }
 
-    applied
+    None
 }
@@ -0,0 +1,67 @@
+use super::{cell_list, cells_with, combinations, value_list};
+use crate::game::{IndexBitSet, Placement, ValueBitSet};
+use crate::prelude::*;
+use crate::solver::candidates::SetOfMoveCandidates;
+use crate::solver::{Deduction, Technique};
+use crate::GameState;
+
+/// Finds two values of a house that are confined to the same two cells.
+pub fn hidden_pairs(state: &GameState, candidates: &SetOfMoveCandidates) -> Option<Deduction> {
+    hidden_subsets(state, candidates, 2, Technique::HiddenPair)
+}
+
+/// Finds three values of a house that are confined to the same three cells.
+pub fn hidden_triples(state: &GameState, candidates: &SetOfMoveCandidates) -> Option<Deduction> {
+    hidden_subsets(state, candidates, 3, Technique::HiddenTriple)
+}
+
+fn hidden_subsets(
+    state: &GameState,
+    candidates: &SetOfMoveCandidates,
+    size: usize,
+    technique: Technique,
+) -> Option<Deduction> {
+    for house in state.game.houses() {
+        let values: Vec<Value> = state
+            .valid_symbols()
+            .iter()
+            .copied()
+            .filter(|&value| {
+                (2..=size).contains(&cells_with(candidates, &house.cells, value).len())
+            })
+            .collect();
+
+        for subset in combinations(&values, size) {
+            let mut cells = IndexBitSet::default();
+            for &value in subset.iter() {
+                cells.union(&cells_with(candidates, &house.cells, value));
+            }
+
+            if cells.len() != size {
+                continue;
+            }
+
+            let hidden = ValueBitSet::from(subset.as_slice());
+            let mut eliminations = Vec::new();
+            for index in cells.iter() {
+                for value in candidates.values(index).without_all(&hidden).iter() {
+                    eliminations.push(Placement::new(value, index));
+                }
+            }
+
+            if eliminations.is_empty() {
+                continue;
+            }
+
+            let reason = format!(
+                "the values {} only appear in {} within {}",
+                value_list(&hidden),
+                cell_list(&state.game, &cells),
+                house
+            );
+            return Some(Deduction::elimination(technique, eliminations, reason));
+        }
+    }
+
+    None
+}
@@ -0,0 +1,119 @@
+use crate::game::cage::prune_sum;
+use crate::game::{IndexBitSet, ValueBitSet};
+use crate::solver::candidates::SetOfMoveCandidates;
+use crate::solver::steps::{candidate_grid, cell_list};
+use crate::solver::{Deduction, Technique};
+use crate::{Cage, GameState, Placement};
+
+/// The sum of the digits of a house.
+const HOUSE_SUM: usize = 45;
+
+/// Applies the rule of 45: as the digits of a house add up to 45, the cells of a house that
+/// are not covered by the cages inside it (the innies) add up to the remainder. Likewise,
+/// the cells of the cages covering a house that stick out of it (the outies) add up to the
+/// excess.
+///
+/// The cages must not overlap, as their sums would be counted twice; otherwise, the rule
+/// is not applied.
+pub fn innies_outies(state: &GameState, candidates: &SetOfMoveCandidates) -> Option<Deduction> {
+    let game = &state.game;
+    let cages: Vec<&Cage> = game.constraints_of::<Cage>().collect();
+    if cages.is_empty() || cages_overlap(&cages) {
+        return None;
+    }
+
+    let grid = candidate_grid(state, candidates);
+    let houses = game.houses();
+
+    for house in houses.iter() {
+        let mut inside = IndexBitSet::default();
+        let mut inside_sum = 0;
+        let mut covering = IndexBitSet::default();
+        let mut covering_sum = 0;
+        for cage in cages.iter() {
+            if cage.cells.is_subset(&house.cells) {
+                inside.union(&cage.cells);
+                inside_sum += cage.sum as usize;
+            }
+            if !cage.cells.with_intersection(&house.cells).is_empty() {
+                covering.union(&cage.cells);
+                covering_sum += cage.sum as usize;
+            }
+        }
+
+        let innies = house.cells.without_all(&inside);
+        if !inside.is_empty() && !innies.is_empty() && inside_sum < HOUSE_SUM {
+            let sum = HOUSE_SUM - inside_sum;
+            let reason = format!(
+                "the cells of {} outside its cages ({}) sum to {}",
+                house,
+                cell_list(game, &innies),
+                sum
+            );
+            if let Some(deduction) = sum_eliminations(state, &grid, &innies, sum, reason) {
+                return Some(deduction);
+            }
+        }
+
+        // Outies are only distinct if they share a house.
+        let outies = covering.without_all(&house.cells);
+        let distinct = houses.iter().any(|other| outies.is_subset(&other.cells));
+        if house.cells.is_subset(&covering)
+            && !outies.is_empty()
+            && distinct
+            && covering_sum > HOUSE_SUM
+        {
+            let sum = covering_sum - HOUSE_SUM;
+            let reason = format!(
+                "the cells of the cages covering {} outside of it ({}) sum to {}",
+                house,
+                cell_list(game, &outies),
+                sum
+            );
+            if let Some(deduction) = sum_eliminations(state, &grid, &outies, sum, reason) {
+                return Some(deduction);
+            }
+        }
+    }
+
+    None
+}
+
+/// Determines whether any cell is covered by two of the cages.
+fn cages_overlap(cages: &[&Cage]) -> bool {
+    let mut covered = IndexBitSet::default();
+    for cage in cages.iter() {
+        if !cage.cells.with_intersection(&covered).is_empty() {
+            return true;
+        }
+        covered.union(&cage.cells);
+    }
+    false
+}
+
+/// Eliminates the candidates of distinct cells that do not fit any combination of the sum.
+fn sum_eliminations(
+    state: &GameState,
+    grid: &[ValueBitSet],
+    cells: &IndexBitSet,
+    sum: usize,
+    reason: String,
+) -> Option<Deduction> {
+    let mut pruned = grid.to_vec();
+    prune_sum(cells, sum, &mut pruned);
+
+    let mut eliminations = Vec::new();
+    for index in cells.with_intersection(&state.empty_cells).iter() {
+        let removed = grid[index as usize].without_all(&pruned[index as usize]);
+        eliminations.extend(removed.iter().map(|value| Placement::new(value, index)));
+    }
+
+    if eliminations.is_empty() {
+        return None;
+    }
+    Some(Deduction::elimination(
+        Technique::InnieOutie,
+        eliminations,
+        reason,
+    ))
+}
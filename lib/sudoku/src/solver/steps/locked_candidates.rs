@@ -0,0 +1,71 @@
+use super::{cell_list, cells_with};
+use crate::game::{HouseKind, Placement};
+use crate::solver::candidates::SetOfMoveCandidates;
+use crate::solver::{Deduction, Technique};
+use crate::GameState;
+
+/// Finds a value whose candidates within a group are confined to another house.
+pub fn pointing(state: &GameState, candidates: &SetOfMoveCandidates) -> Option<Deduction> {
+    locked_candidates(state, candidates, Technique::Pointing)
+}
+
+/// Finds a value whose candidates within a row or column are confined to a group.
+pub fn claiming(state: &GameState, candidates: &SetOfMoveCandidates) -> Option<Deduction> {
+    locked_candidates(state, candidates, Technique::Claiming)
+}
+
+fn locked_candidates(
+    state: &GameState,
+    candidates: &SetOfMoveCandidates,
+    technique: Technique,
+) -> Option<Deduction> {
+    let houses = state.game.houses();
+    let is_group = |kind: HouseKind| matches!(kind, HouseKind::Group(_));
+
+    for source in houses.iter() {
+        // Pointing starts from a group, claiming from a row or column.
+        if is_group(source.kind) != (technique == Technique::Pointing) {
+            continue;
+        }
+
+        for value in state.valid_symbols() {
+            let cells = cells_with(candidates, &source.cells, *value);
+            if cells.len() < 2 {
+                continue;
+            }
+
+            for target in houses.iter() {
+                if target.kind == source.kind || !cells.is_subset(&target.cells) {
+                    continue;
+                }
+
+                // Claiming always confines to a group.
+                if technique == Technique::Claiming && !is_group(target.kind) {
+                    continue;
+                }
+
+                let rest = target.cells.without_all(&source.cells);
+                let eliminated = cells_with(candidates, &rest, *value);
+                if eliminated.is_empty() {
+                    continue;
+                }
+
+                let reason = format!(
+                    "within {}, {} is confined to {} ({}), so it cannot appear elsewhere in {}",
+                    source,
+                    value,
+                    target,
+                    cell_list(&state.game, &cells),
+                    target
+                );
+                let eliminations = eliminated
+                    .iter()
+                    .map(|index| Placement::new(*value, index))
+                    .collect();
+                return Some(Deduction::elimination(technique, eliminations, reason));
+            }
+        }
+    }
+
+    None
+}
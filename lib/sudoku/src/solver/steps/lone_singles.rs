@@ -1,21 +1,28 @@
 use crate::game::Placement;
 use crate::solver::candidates::SetOfMoveCandidates;
+use crate::solver::{Deduction, Technique};
 use crate::GameState;
 
-pub fn lone_singles(state: &mut GameState, candidates: &SetOfMoveCandidates) -> Vec<Placement> {
-    let mut applied = Vec::new();
-
-    for candidate in candidates.iter() {
-        if !candidate.is_trivial() {
+/// Finds a cell that has only a single candidate left.
+pub fn lone_singles(state: &GameState, candidates: &SetOfMoveCandidates) -> Option<Deduction> {
+    for index in state.empty_cells.iter() {
+        let values = candidates.values(index);
+        if values.len() != 1 {
             continue;
         }
 
-        for r#move in &candidate.moves {
-            state.apply_move(r#move);
-        }
-
-        applied.extend(candidate.moves);
+        let value = values.iter().next().unwrap();
+        let reason = format!(
+            "{} has {} as its only remaining candidate",
+            state.game.cell_name(index),
+            value
+        );
+        return Some(Deduction::placement(
+            Technique::LoneSingle,
+            Placement::new(value, index),
+            reason,
+        ));
     }
 
-    applied
+    None
 }
@@ -0,0 +1,64 @@
+use super::{cell_list, combinations, value_list};
+use crate::game::{IndexBitSet, Placement, ValueBitSet};
+use crate::prelude::*;
+use crate::solver::candidates::SetOfMoveCandidates;
+use crate::solver::{Deduction, Technique};
+use crate::GameState;
+
+/// Finds two cells of a house that share the same two candidates.
+pub fn naked_pairs(state: &GameState, candidates: &SetOfMoveCandidates) -> Option<Deduction> {
+    naked_subsets(state, candidates, 2, Technique::NakedPair)
+}
+
+/// Finds three cells of a house that share three candidates between them.
+pub fn naked_triples(state: &GameState, candidates: &SetOfMoveCandidates) -> Option<Deduction> {
+    naked_subsets(state, candidates, 3, Technique::NakedTriple)
+}
+
+fn naked_subsets(
+    state: &GameState,
+    candidates: &SetOfMoveCandidates,
+    size: usize,
+    technique: Technique,
+) -> Option<Deduction> {
+    for house in state.game.houses() {
+        let open: Vec<Index> = house
+            .cells
+            .iter()
+            .filter(|&index| (2..=size).contains(&candidates.values(index).len()))
+            .collect();
+
+        for subset in combinations(&open, size) {
+            let mut values = ValueBitSet::default();
+            for &index in subset.iter() {
+                values.union(&candidates.values(index));
+            }
+
+            if values.len() != size {
+                continue;
+            }
+
+            let cells = IndexBitSet::from(subset.as_slice());
+            let mut eliminations = Vec::new();
+            for index in house.cells.without_all(&cells).iter() {
+                for value in candidates.values(index).with_intersection(&values).iter() {
+                    eliminations.push(Placement::new(value, index));
+                }
+            }
+
+            if eliminations.is_empty() {
+                continue;
+            }
+
+            let reason = format!(
+                "{} contain only the candidates {} within {}",
+                cell_list(&state.game, &cells),
+                value_list(&values),
+                house
+            );
+            return Some(Deduction::elimination(technique, eliminations, reason));
+        }
+    }
+
+    None
+}
@@ -0,0 +1,77 @@
+use super::{peers, value_list};
+use crate::game::Placement;
+use crate::prelude::*;
+use crate::solver::candidates::SetOfMoveCandidates;
+use crate::solver::{Deduction, Technique};
+use crate::GameState;
+
+/// Finds a bi-value pivot cell `{a, b}` that sees two bi-value pincers `{a, c}` and `{b, c}`.
+/// Whichever value the pivot takes, one of the pincers must be `c`, so `c` can be eliminated
+/// from every cell that sees both pincers.
+pub fn xy_wing(state: &GameState, candidates: &SetOfMoveCandidates) -> Option<Deduction> {
+    let bivalue: Vec<Index> = state
+        .empty_cells
+        .iter()
+        .filter(|&index| candidates.values(index).len() == 2)
+        .collect();
+
+    for &pivot in bivalue.iter() {
+        let pivot_values = candidates.values(pivot);
+        let pivot_peers = peers(state, pivot);
+
+        let wings: Vec<Index> = bivalue
+            .iter()
+            .copied()
+            .filter(|&index| pivot_peers.contains(index))
+            .collect();
+
+        for &first in wings.iter() {
+            let first_values = candidates.values(first);
+            if first_values.with_intersection(&pivot_values).len() != 1 {
+                continue;
+            }
+
+            // The value shared by both pincers, and the one the second pincer shares with the pivot.
+            let common = first_values.without_all(&pivot_values);
+            let other = pivot_values.without_all(&first_values);
+            let second_values = common.with_union(&other);
+
+            for &second in wings.iter() {
+                if second == first || candidates.values(second) != second_values {
+                    continue;
+                }
+
+                let value = common.iter().next().unwrap();
+                let seen = peers(state, first).with_intersection(&peers(state, second));
+                let eliminations: Vec<Placement> = seen
+                    .iter()
+                    .filter(|&index| index != pivot)
+                    .map(|index| Placement::new(value, index))
+                    .filter(|candidate| candidates.contains(candidate))
+                    .collect();
+
+                if eliminations.is_empty() {
+                    continue;
+                }
+
+                let reason = format!(
+                    "pivot {} ({}) sees pincers {} ({}) and {} ({}), so one of the pincers must be {}",
+                    state.game.cell_name(pivot),
+                    value_list(&pivot_values),
+                    state.game.cell_name(first),
+                    value_list(&first_values),
+                    state.game.cell_name(second),
+                    value_list(&second_values),
+                    value
+                );
+                return Some(Deduction::elimination(
+                    Technique::XYWing,
+                    eliminations,
+                    reason,
+                ));
+            }
+        }
+    }
+
+    None
+}
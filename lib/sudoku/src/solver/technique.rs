@@ -0,0 +1,195 @@
+use std::fmt::{Display, Formatter};
+
+/// The logical solving techniques known to the solver.
+///
+/// Techniques are ordered by their difficulty; the solver always attempts the easiest
+/// technique first.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Technique {
+    /// A cell shaded odd or even only takes digits of that parity.
+    OddEven,
+    /// A value can only go into a single cell of a house.
+    HiddenSingle,
+    /// The candidates of a killer cage are restricted to the digit combinations that add up
+    /// to its sum.
+    CageCombination,
+    /// The candidates along a thermometer are bounded by the candidates of the cells
+    /// before and after them.
+    Thermometer,
+    /// The circle of an arrow and the cells of its shaft are bounded by the smallest and
+    /// largest possible sums of the shaft.
+    Arrow,
+    /// The candidates of cells joined by a Kropki dot must be consecutive (white) or
+    /// in a 1:2 ratio (black), and possibly must not be where no dot is given.
+    Kropki,
+    /// The candidates of cells joined by a V or an X must add up to 5 or 10, and possibly
+    /// must not where no marker is given.
+    XV,
+    /// The candidates of a cell are ruled out by the candidates of its orthogonal neighbors
+    /// in a non-consecutive game, e.g. a 5 next to a cell that can only take 4 or 6.
+    NonConsecutive,
+    /// The candidates of cells joined by a `<` or `>` sign are bounded by each other.
+    GreaterThan,
+    /// The candidates of neighbors along a German whispers line differ by at least 5,
+    /// which rules out the 5 entirely.
+    GermanWhispers,
+    /// The candidates of a renban line are confined to the runs of consecutive digits
+    /// that fit all of its cells.
+    Renban,
+    /// The candidates of cells mirrored along a palindrome line are shared between them.
+    Palindrome,
+    /// A cell has only a single remaining candidate.
+    LoneSingle,
+    /// The candidates along a little killer diagonal are restricted to the digits that
+    /// complete one of the feasible sums of the other cells.
+    LittleKiller,
+    /// The 1 and the 9 of a row or column with a sandwich clue are placed so that the
+    /// cells between them can add up to the clue, and those cells are bounded by it.
+    Sandwich,
+    /// The first digit of a row or column with an X-sum clue is limited to the window
+    /// lengths that can add up to the clue, and the cells of the window are bounded by it.
+    XSum,
+    /// The candidates of a row or column with a skyscraper clue are restricted to the
+    /// permutations that show the number of digits given by the clue.
+    Skyscraper,
+    /// The candidates of cells are ruled out by a user-defined
+    /// [`Constraint`](crate::Constraint) that does not credit a technique of its own.
+    Custom,
+    /// The cells of a house that are not covered by the cages inside it (or the cells of
+    /// cages that stick out of it) add up to the remainder of 45.
+    InnieOutie,
+    /// The candidates of a value within a group are confined to a single row or column
+    /// (or another group), eliminating the value from the rest of that house.
+    Pointing,
+    /// The candidates of a value within a row or column are confined to a single group,
+    /// eliminating the value from the rest of that group.
+    Claiming,
+    /// Two cells of a house share the same two candidates.
+    NakedPair,
+    /// A value is confined to the same two columns in two rows (or vice versa).
+    XWing,
+    /// Two values of a house are confined to the same two cells.
+    HiddenPair,
+    /// Three cells of a house share three candidates between them.
+    NakedTriple,
+    /// A value is confined to the same three columns in three rows (or vice versa).
+    Swordfish,
+    /// Three values of a house are confined to the same three cells.
+    HiddenTriple,
+    /// A bi-value pivot sees two bi-value pincers that share a common candidate.
+    XYWing,
+}
+
+impl Technique {
+    /// All techniques in the order in which the solver attempts them.
+    pub const ALL: [Technique; 28] = [
+        Technique::OddEven,
+        Technique::HiddenSingle,
+        Technique::CageCombination,
+        Technique::Thermometer,
+        Technique::Arrow,
+        Technique::Kropki,
+        Technique::XV,
+        Technique::NonConsecutive,
+        Technique::GreaterThan,
+        Technique::GermanWhispers,
+        Technique::Renban,
+        Technique::Palindrome,
+        Technique::LoneSingle,
+        Technique::LittleKiller,
+        Technique::Sandwich,
+        Technique::XSum,
+        Technique::Skyscraper,
+        Technique::Custom,
+        Technique::InnieOutie,
+        Technique::Pointing,
+        Technique::Claiming,
+        Technique::NakedPair,
+        Technique::XWing,
+        Technique::HiddenPair,
+        Technique::NakedTriple,
+        Technique::Swordfish,
+        Technique::HiddenTriple,
+        Technique::XYWing,
+    ];
+
+    /// Returns all techniques up to and including the specified one, e.g. `up_to(LoneSingle)`
+    /// for "singles only".
+    pub fn up_to(last: Technique) -> &'static [Technique] {
+        let position = Technique::ALL.iter().position(|&t| t == last).unwrap();
+        &Technique::ALL[..=position]
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Technique::OddEven => "Odd/Even",
+            Technique::HiddenSingle => "Hidden Single",
+            Technique::CageCombination => "Cage Combination",
+            Technique::Thermometer => "Thermometer",
+            Technique::Arrow => "Arrow",
+            Technique::Kropki => "Kropki",
+            Technique::XV => "XV",
+            Technique::NonConsecutive => "Non-Consecutive",
+            Technique::GreaterThan => "Greater Than",
+            Technique::GermanWhispers => "German Whispers",
+            Technique::Renban => "Renban",
+            Technique::Palindrome => "Palindrome",
+            Technique::LoneSingle => "Lone Single",
+            Technique::LittleKiller => "Little Killer",
+            Technique::Sandwich => "Sandwich",
+            Technique::XSum => "X-Sum",
+            Technique::Skyscraper => "Skyscraper",
+            Technique::Custom => "Custom Constraint",
+            Technique::InnieOutie => "Innie/Outie",
+            Technique::Pointing => "Pointing",
+            Technique::Claiming => "Claiming",
+            Technique::NakedPair => "Naked Pair",
+            Technique::XWing => "X-Wing",
+            Technique::HiddenPair => "Hidden Pair",
+            Technique::NakedTriple => "Naked Triple",
+            Technique::Swordfish => "Swordfish",
+            Technique::HiddenTriple => "Hidden Triple",
+            Technique::XYWing => "XY-Wing",
+        }
+    }
+
+    /// The difficulty rating of the technique, modelled after the Sudoku Explainer (SE) scale.
+    pub fn rating(&self) -> f32 {
+        match self {
+            Technique::OddEven => 1.0,
+            Technique::HiddenSingle => 1.5,
+            Technique::CageCombination => 2.0,
+            Technique::Thermometer => 2.1,
+            Technique::Arrow => 2.2,
+            Technique::Kropki => 2.2,
+            Technique::XV => 2.2,
+            Technique::NonConsecutive => 2.2,
+            Technique::GreaterThan => 2.2,
+            Technique::GermanWhispers => 2.2,
+            Technique::Renban => 2.2,
+            Technique::Palindrome => 2.2,
+            Technique::LoneSingle => 2.3,
+            Technique::LittleKiller => 2.4,
+            Technique::Sandwich => 2.4,
+            Technique::XSum => 2.4,
+            Technique::Skyscraper => 2.4,
+            Technique::Custom => 2.4,
+            Technique::InnieOutie => 2.5,
+            Technique::Pointing => 2.6,
+            Technique::Claiming => 2.8,
+            Technique::NakedPair => 3.0,
+            Technique::XWing => 3.2,
+            Technique::HiddenPair => 3.4,
+            Technique::NakedTriple => 3.6,
+            Technique::Swordfish => 3.8,
+            Technique::HiddenTriple => 4.0,
+            Technique::XYWing => 4.2,
+        }
+    }
+}
+
+impl Display for Technique {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
@@ -0,0 +1,531 @@
+//! Transformations that map games onto equivalent games, e.g. rotations, mirrors,
+//! row and column permutations and digit relabeling.
+//!
+//! A [`Transform`] is applied to the clues, the current values and the group layout alike,
+//! so that a nonomino layout rotates together with its clues.
+//!
+//! ## Example
+//!
+//! ```
+//! # use sudoku::{Game, GameState};
+//! # use sudoku::solver::count_solutions;
+//! # use sudoku::transform::Transform;
+//! let game = Game::new_example_nonomino();
+//! let rotated = Transform::rotate().game(&game).unwrap();
+//! assert_eq!(count_solutions(&GameState::new(rotated), 2), 1);
+//! ```
+
+mod canonical;
+mod transform_error;
+
+pub use canonical::{canonicalize, fingerprint, Fingerprint};
+pub use transform_error::TransformError;
+
+use crate::prelude::*;
+use crate::random::Random;
+use crate::{Game, GameState, IndexBitSet, State};
+use std::rc::Rc;
+
+/// The edge length of the (square) board.
+const SIZE: usize = 9;
+
+/// The number of rows in a band, or columns in a stack.
+const BAND: usize = 3;
+
+/// A permutation of the cells and digits of the board.
+///
+/// Transforms are created from the elementary operations below and combined with
+/// [`then`](Self::then).
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Transform {
+    /// For every cell of the transformed board, the cell of the original board it is taken from.
+    source: [Index; SIZE * SIZE],
+    /// For every digit, the digit it is relabeled to.
+    digits: [Value; SIZE],
+}
+
+impl Transform {
+    /// The transform that leaves the board unchanged.
+    pub fn identity() -> Transform {
+        Transform::from_cells(|x, y| (x, y))
+    }
+
+    /// Rotates the board clockwise by 90°.
+    pub fn rotate() -> Transform {
+        Transform::from_cells(|x, y| (y, SIZE - 1 - x))
+    }
+
+    /// Mirrors the board across the horizontal axis, swapping top and bottom.
+    pub fn mirror_horizontal() -> Transform {
+        Transform::from_cells(|x, y| (x, SIZE - 1 - y))
+    }
+
+    /// Mirrors the board across the vertical axis, swapping left and right.
+    pub fn mirror_vertical() -> Transform {
+        Transform::from_cells(|x, y| (SIZE - 1 - x, y))
+    }
+
+    /// Mirrors the board across the main diagonal.
+    pub fn transpose() -> Transform {
+        Transform::from_cells(|x, y| (y, x))
+    }
+
+    /// Returns the eight symmetries of the square, starting with the identity.
+    pub fn dihedral() -> Vec<Transform> {
+        let mut transforms = Vec::with_capacity(8);
+        let mut rotation = Transform::identity();
+        for _ in 0..4 {
+            transforms.push(rotation.clone());
+            transforms.push(rotation.then(&Transform::transpose()));
+            rotation = rotation.then(&Transform::rotate());
+        }
+        transforms
+    }
+
+    /// Reorders the bands, i.e. the horizontal strips of three rows.
+    /// Band `i` of the result is band `order[i]` of the original board.
+    pub fn permute_bands(order: [usize; BAND]) -> Transform {
+        assert_permutation(&order);
+        Transform::from_cells(|x, y| (x, order[y / BAND] * BAND + y % BAND))
+    }
+
+    /// Reorders the stacks, i.e. the vertical strips of three columns.
+    /// Stack `i` of the result is stack `order[i]` of the original board.
+    pub fn permute_stacks(order: [usize; BAND]) -> Transform {
+        assert_permutation(&order);
+        Transform::from_cells(|x, y| (order[x / BAND] * BAND + x % BAND, y))
+    }
+
+    /// Reorders the rows within the specified band.
+    pub fn permute_rows(band: usize, order: [usize; BAND]) -> Transform {
+        assert!(band < BAND);
+        assert_permutation(&order);
+        Transform::from_cells(|x, y| match y / BAND == band {
+            true => (x, band * BAND + order[y % BAND]),
+            false => (x, y),
+        })
+    }
+
+    /// Reorders the columns within the specified stack.
+    pub fn permute_columns(stack: usize, order: [usize; BAND]) -> Transform {
+        assert!(stack < BAND);
+        assert_permutation(&order);
+        Transform::from_cells(|x, y| match x / BAND == stack {
+            true => (stack * BAND + order[x % BAND], y),
+            false => (x, y),
+        })
+    }
+
+    /// Relabels the digits, replacing each digit `d` with `digits[d - 1]`.
+    ///
+    /// ## Panics
+    /// Panics if `digits` is not a permutation of the digits 1 to 9.
+    pub fn relabel(digits: [Value; SIZE]) -> Transform {
+        let mut seen = ValueBitSet::default();
+        for digit in digits {
+            seen.insert(digit);
+        }
+        assert_eq!(seen.len(), SIZE, "The digits must be a permutation");
+
+        Transform {
+            digits,
+            ..Transform::identity()
+        }
+    }
+
+    /// Creates a random transform that preserves the standard 3x3 box layout, combining a
+    /// symmetry of the square, band, stack, row and column permutations and a relabeling.
+    pub fn random(seed: u64) -> Transform {
+        let mut random = Random::new(seed);
+        let dihedral = Transform::dihedral();
+        let mut transform = dihedral[random.below(dihedral.len())].clone();
+
+        transform = transform
+            .then(&Transform::permute_bands(random_order(&mut random)))
+            .then(&Transform::permute_stacks(random_order(&mut random)));
+        for i in 0..BAND {
+            transform = transform
+                .then(&Transform::permute_rows(i, random_order(&mut random)))
+                .then(&Transform::permute_columns(i, random_order(&mut random)));
+        }
+
+        let mut digits = Transform::identity().digits;
+        random.shuffle(&mut digits);
+        transform.then(&Transform::relabel(digits))
+    }
+
+    /// Combines two transforms, applying `self` first and `next` second.
+    pub fn then(&self, next: &Transform) -> Transform {
+        let mut source = [0; SIZE * SIZE];
+        for (index, cell) in source.iter_mut().enumerate() {
+            *cell = self.source[next.source[index] as usize];
+        }
+
+        let digits = self
+            .digits
+            .map(|digit| next.digits[digit.get() as usize - 1]);
+        Transform { source, digits }
+    }
+
+    /// Returns the cell of the transformed board that the specified cell is moved to.
+    pub fn target(&self, index: Index) -> Index {
+        self.source
+            .iter()
+            .position(|&source| source == index)
+            .unwrap() as Index
+    }
+
+    /// Returns the digit the specified digit is relabeled to.
+    pub fn digit(&self, value: Value) -> Value {
+        self.digits[value.get() as usize - 1]
+    }
+
+    /// Transforms the values of a state.
+    pub fn state(&self, state: &State) -> State {
+        let values = state.values();
+        let mut transformed = [None; SIZE * SIZE];
+        for (index, value) in transformed.iter_mut().enumerate() {
+            *value = values[self.source[index] as usize].map(|value| self.digit(value));
+        }
+        State::new(transformed)
+    }
+
+    /// Transforms a set of cells.
+    pub fn cells(&self, cells: &IndexBitSet) -> IndexBitSet {
+        let mut transformed = IndexBitSet::default();
+        for index in 0..(SIZE * SIZE) as Index {
+            if cells.contains(self.source[index as usize]) {
+                transformed.insert(index);
+            }
+        }
+        transformed
+    }
+
+    /// Transforms the clues and the group layout of a game.
+    ///
+    /// Every constraint of the game is moved by its [`Constraint::transform`]: houses, cages,
+    /// thermometers, arrows, lines, markers and shaded cells move with their cells, and outside
+    /// clues with their rows and columns.
+    ///
+    /// Fails if the transformed game would not be equivalent: if the transformed groups no longer
+    /// form a valid layout, e.g. when the bands of a nonomino layout are reordered and its regions
+    /// fall apart, if the digits are relabeled although the clues depend on them, or if the
+    /// geometry a rule or clue depends on is not preserved. Custom constraints cannot be moved
+    /// unless they implement [`Constraint::transform`], so games with such constraints only
+    /// support the identity.
+    ///
+    /// [`Constraint::transform`]: crate::Constraint::transform
+    pub fn game(&self, game: &Game) -> Result<Game, TransformError> {
+        if *self == Transform::identity() {
+            return Ok(game.clone());
+        }
+
+        let constraints = game
+            .constraints()
+            .iter()
+            .map(|constraint| constraint.transform(self))
+            .collect::<Result<_, _>>()?;
+        let state = self.state(&game.fork_state());
+        Ok(Game::from_constraints(
+            *state.values(),
+            constraints,
+            game.is_chaos(),
+        )?)
+    }
+
+    /// Transforms a game in progress, including its current values.
+    pub fn game_state(&self, state: &GameState) -> Result<GameState, TransformError> {
+        let game = self.game(&state.game)?;
+        let state = self.state(&state.state);
+        Ok(GameState {
+            empty_cells: state.empty_cells(),
+            game: Rc::new(game),
+            state,
+        })
+    }
+
+    /// Determines whether the transform relabels any digit.
+    pub fn relabels(&self) -> bool {
+        self.digits != Transform::identity().digits
+    }
+
+    /// Determines whether the transform is one of the symmetries of the square, which
+    /// preserve the neighborhood of every cell.
+    pub fn is_symmetry(&self) -> bool {
+        Transform::dihedral()
+            .iter()
+            .any(|symmetry| symmetry.source == self.source)
+    }
+
+    /// Fails if the transform relabels any digit, for constraints that depend on the digits.
+    pub(crate) fn keep_digits(&self) -> Result<(), TransformError> {
+        match self.relabels() {
+            true => Err(TransformError::RelabeledDigits),
+            false => Ok(()),
+        }
+    }
+
+    /// Fails unless the transform is a symmetry of the square, for constraints that depend on
+    /// the neighborhood of the cells.
+    pub(crate) fn keep_neighborhoods(&self) -> Result<(), TransformError> {
+        match self.is_symmetry() {
+            true => Ok(()),
+            false => Err(TransformError::NotASymmetry),
+        }
+    }
+
+    /// Moves a pair of orthogonally adjacent cells. Fails if the cells are no longer adjacent.
+    pub(crate) fn adjacent(
+        &self,
+        (a, b): (Index, Index),
+    ) -> Result<(Index, Index), TransformError> {
+        let (a, b) = (self.target(a), self.target(b));
+        match orthogonal_neighbors(a, SIZE, SIZE).contains(&b) {
+            true => Ok((a, b)),
+            false => Err(TransformError::NotAdjacent(a, b)),
+        }
+    }
+
+    fn from_cells<F>(map: F) -> Transform
+    where
+        F: Fn(Coordinate, Coordinate) -> (Coordinate, Coordinate),
+    {
+        let mut source = [0; SIZE * SIZE];
+        for (index, cell) in source.iter_mut().enumerate() {
+            let (x, y) = map(index % SIZE, index / SIZE);
+            *cell = crate::prelude::index(x, y, SIZE) as Index;
+        }
+
+        let digits = std::array::from_fn(|digit| Value::new(digit as u8 + 1).unwrap());
+        Transform { source, digits }
+    }
+}
+
+fn assert_permutation(order: &[usize; BAND]) {
+    let mut sorted = *order;
+    sorted.sort_unstable();
+    assert_eq!(sorted, [0, 1, 2], "The order must be a permutation");
+}
+
+fn random_order(random: &mut Random) -> [usize; BAND] {
+    let mut order = [0, 1, 2];
+    random.shuffle(&mut order);
+    order
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::{count_solutions, rate, solve};
+    use crate::{
+        Cage, Constraint, Diagonal, Edge, LittleKiller, Marker, NegativeMarker, OutsideClue, Side,
+        Thermometer,
+    };
+
+    #[test]
+    fn rotating_four_times_is_identity() {
+        let rotate = Transform::rotate();
+        let full = rotate.then(&rotate).then(&rotate).then(&rotate);
+        assert_eq!(full, Transform::identity());
+
+        let mirrors = Transform::mirror_horizontal().then(&Transform::mirror_vertical());
+        assert_eq!(mirrors, rotate.then(&rotate));
+    }
+
+    #[test]
+    fn dihedral_symmetries_are_distinct() {
+        let transforms = Transform::dihedral();
+        for (i, a) in transforms.iter().enumerate() {
+            for b in transforms[i + 1..].iter() {
+                assert_ne!(a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn rotation_moves_cells_clockwise() {
+        let rotate = Transform::rotate();
+        assert_eq!(rotate.target(0), 8);
+        assert_eq!(rotate.target(8), 80);
+        assert_eq!(Transform::transpose().target(1), 9);
+    }
+
+    #[test]
+    fn relabels_digits() {
+        let mut digits = Transform::identity().digits;
+        digits.swap(0, 1);
+        let relabel = Transform::relabel(digits);
+
+        let game = relabel.game(&Game::new_example()).unwrap();
+        assert_eq!(game.cell(0, 0), Value::new(5));
+        assert_eq!(game.cell(3, 1), Value::new(2));
+    }
+
+    #[test]
+    fn transformed_solution_solves_transformed_game() {
+        let state = GameState::new(Game::new_example());
+        let solution = solve(&state);
+
+        for seed in 0..5 {
+            let transform = Transform::random(seed);
+            let transformed = transform.game_state(&state).unwrap();
+            let expected = transform.state(&solution.state);
+
+            assert_eq!(solve(&transformed).state.values(), expected.values());
+            assert_eq!(rate(&transformed).hardest, rate(&state).hardest);
+        }
+    }
+
+    #[test]
+    fn layouts_move_with_clues() {
+        let game = Game::new_example_nonomino();
+        for transform in Transform::dihedral() {
+            let transformed = transform.game(&game).unwrap();
+            assert_eq!(count_solutions(&GameState::new(transformed), 2), 1);
+        }
+
+        let reordered = Transform::permute_bands([1, 0, 2]).game(&game);
+        assert!(reordered.is_err());
+    }
+
+    #[test]
+    fn constraints_move_with_cells() {
+        let game = Game::new_example_killer();
+        let rotated = Transform::rotate().game(&game).unwrap();
+
+        assert_eq!(
+            rotated.constraints_of::<Cage>().count(),
+            game.constraints_of::<Cage>().count()
+        );
+        assert_eq!(count_solutions(&GameState::new(rotated), 2), 1);
+
+        let game = Game::new_example_thermo();
+        let mirrored = Transform::mirror_vertical().game(&game).unwrap();
+        assert_eq!(
+            mirrored
+                .constraints_of::<Thermometer>()
+                .next()
+                .unwrap()
+                .cells,
+            vec![7, 8, 16]
+        );
+        assert_eq!(count_solutions(&GameState::new(mirrored), 2), 1);
+
+        let game = Game::new_example_little_killer();
+        let mirrored = Transform::mirror_horizontal().game(&game).unwrap();
+        assert_eq!(
+            mirrored
+                .constraints_of::<LittleKiller>()
+                .next()
+                .unwrap()
+                .direction,
+            Diagonal::DownRight
+        );
+        assert_eq!(count_solutions(&GameState::new(mirrored), 2), 1);
+
+        let game = Game::new_example_x_sums();
+        let rotated = Transform::rotate().game(&game).unwrap();
+        let clue = rotated.constraints_of::<OutsideClue>().next().unwrap();
+        assert_eq!(clue.side, Side::Right);
+        assert_eq!(clue.line, 0);
+        assert_eq!(count_solutions(&GameState::new(rotated), 2), 1);
+    }
+
+    #[test]
+    fn rejects_transforms_that_change_the_rules() {
+        let killer = Game::new_example_killer();
+        let relabeled = Transform::random(0).game(&killer);
+        assert_eq!(relabeled.err(), Some(TransformError::RelabeledDigits));
+
+        // Cages only depend on their cells, which any permutation preserves.
+        let reordered = Transform::permute_bands([1, 0, 2]).game(&killer).unwrap();
+        assert_eq!(count_solutions(&GameState::new(reordered), 2), 1);
+
+        let anti_knight = Game::new_example_anti_knight();
+        let reordered = Transform::permute_bands([1, 0, 2]).game(&anti_knight);
+        assert_eq!(reordered.err(), Some(TransformError::NotASymmetry));
+
+        // Swapping two columns scrambles the order of every row.
+        let x_sums = Game::new_example_x_sums();
+        let reordered = Transform::permute_columns(0, [1, 0, 2]).game(&x_sums);
+        assert_eq!(reordered.err(), Some(TransformError::BrokenLine));
+    }
+
+    #[test]
+    fn rejects_markers_between_cells_no_longer_adjacent() {
+        let kropki = Game::new_example_kropki();
+        for seed in 0..4 {
+            assert!(Transform::random(seed).game(&kropki).is_err());
+        }
+
+        // Cells without a dot must remain neighbors for the negative constraint to carry over.
+        let mut negative = Game::new_empty();
+        negative.add_constraint(NegativeMarker::new(Marker::WhiteDot, &[]));
+        let reordered = Transform::permute_bands([1, 0, 2]).game(&negative);
+        assert_eq!(reordered.err(), Some(TransformError::NotASymmetry));
+        let rotated = Transform::rotate().game(&kropki).unwrap();
+        assert_eq!(count_solutions(&GameState::new(rotated), 2), 1);
+
+        // Reordering the bands separates the rows on either side of a band's border.
+        let mut dots = Game::new_empty();
+        dots.add_constraints(kropki.constraints_of::<Edge>().copied());
+        let reordered = Transform::permute_bands([1, 0, 2]).game(&dots);
+        assert!(matches!(reordered, Err(TransformError::NotAdjacent(..))));
+    }
+
+    #[test]
+    fn rejects_signs_between_cells_no_longer_adjacent() {
+        let greater_than = Game::new_example_greater_than();
+        for seed in 0..5 {
+            assert!(Transform::random(seed).game(&greater_than).is_err());
+        }
+
+        // The signs lie within the boxes, which reordering the bands keeps intact.
+        let reordered = Transform::permute_bands([1, 0, 2])
+            .game(&greater_than)
+            .unwrap();
+        assert_eq!(count_solutions(&GameState::new(reordered), 2), 1);
+
+        let reordered = Transform::permute_rows(0, [1, 0, 2]).game(&greater_than);
+        assert!(matches!(reordered, Err(TransformError::NotAdjacent(..))));
+    }
+
+    #[test]
+    fn rejects_transforms_that_bend_diagonals() {
+        let little_killer = Game::new_example_little_killer();
+        for seed in 0..4 {
+            assert!(Transform::random(seed).game(&little_killer).is_err());
+        }
+
+        let reordered = Transform::permute_bands([1, 0, 2]).game(&little_killer);
+        assert_eq!(reordered.err(), Some(TransformError::BrokenLine));
+
+        let rotated = Transform::rotate().game(&little_killer).unwrap();
+        assert_eq!(count_solutions(&GameState::new(rotated), 2), 1);
+    }
+
+    #[test]
+    fn rejects_transforms_of_custom_constraints() {
+        struct Even(Index);
+
+        impl Constraint for Even {
+            fn cells(&self) -> IndexBitSet {
+                IndexBitSet::default().with_value(self.0)
+            }
+
+            fn check(&self, values: &[ValueOption]) -> bool {
+                values[self.0 as usize].is_none_or(|value| value.get() % 2 == 0)
+            }
+
+            fn prune(&self, _candidates: &mut [ValueBitSet]) {}
+        }
+
+        let mut game = Game::new_example();
+        game.add_constraint(Even(2));
+        let rotated = Transform::rotate().game(&game);
+        assert_eq!(rotated.err(), Some(TransformError::CustomConstraints));
+
+        let unchanged = Transform::identity().game(&game).unwrap();
+        assert_eq!(unchanged.constraints_of::<Even>().count(), 1);
+    }
+}
@@ -0,0 +1,248 @@
+use crate::Game;
+use std::collections::HashSet;
+use std::fmt::{Display, Formatter};
+
+/// A stable fingerprint of a puzzle that is shared by all of its equivalent forms.
+///
+/// The fingerprint is a 64-bit FNV-1a hash of the canonical form. Unlike the hashers of the
+/// standard library, it does not change between platforms or versions, so fingerprints can
+/// be stored alongside a puzzle corpus.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Fingerprint(pub u64);
+
+/// Returns the canonical form of a standard Sudoku game, i.e. the lexicographically minimal
+/// representative among all games obtained by transposing the board, permuting bands, stacks,
+/// rows within bands and columns within stacks, and relabeling the digits.
+///
+/// Games are compared by their clues in row-major order, with empty cells counting as zero.
+/// Two games are equivalent if and only if their canonical forms are equal. Returns `None`
+/// if the game does not use the standard 3x3 box layout or has constraints such as cages.
+///
+/// ## Example
+///
+/// ```
+/// # use sudoku::Game;
+/// # use sudoku::transform::{canonicalize, Transform};
+/// let game = Game::new_example();
+/// let rotated = Transform::rotate().game(&game).unwrap();
+///
+/// let canonical = canonicalize(&game).unwrap();
+/// assert!(canonicalize(&rotated).unwrap().fork_state() == canonical.fork_state());
+/// ```
+pub fn canonicalize(game: &Game) -> Option<Game> {
+    canonical_values(game).map(Game::new)
+}
+
+/// Returns the fingerprint of a standard Sudoku game, or `None` if the game is not a
+/// standard Sudoku. See [`canonicalize`].
+pub fn fingerprint(game: &Game) -> Option<Fingerprint> {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0100_0000_01b3;
+
+    let values = canonical_values(game)?;
+    let hash = values.iter().fold(OFFSET_BASIS, |hash, &value| {
+        (hash ^ value as u64).wrapping_mul(PRIME)
+    });
+    Some(Fingerprint(hash))
+}
+
+impl Display for Fingerprint {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:016x}", self.0)
+    }
+}
+
+type Grid = [[u8; 9]; 9];
+
+fn canonical_values(game: &Game) -> Option<[u8; 81]> {
+    if !has_standard_layout(game) {
+        return None;
+    }
+
+    let values = game.fork_state();
+    let mut grid = [[0; 9]; 9];
+    for (index, value) in values.values().iter().enumerate() {
+        grid[index / 9][index % 9] = value.map_or(0, |value| value.get());
+    }
+
+    let mut transposed = [[0; 9]; 9];
+    for (y, row) in grid.iter().enumerate() {
+        for (x, &value) in row.iter().enumerate() {
+            transposed[x][y] = value;
+        }
+    }
+
+    let mut search = Search {
+        grid,
+        columns: [0; 9],
+        best: [[u8::MAX; 9]; 9],
+    };
+    for grid in [grid, transposed] {
+        search.grid = grid;
+        for columns in column_orders() {
+            search.columns = columns;
+            search.rows(0, 0, &mut [0; 9], [0; 10], 1);
+        }
+    }
+
+    let mut values = [0; 81];
+    for (index, value) in values.iter_mut().enumerate() {
+        *value = search.best[index / 9][index % 9];
+    }
+    Some(values)
+}
+
+fn has_standard_layout(game: &Game) -> bool {
+    let boxes: HashSet<_> = Game::new_empty().groups().iter().map(|g| **g).collect();
+    let groups = game.groups();
+    groups.len() == boxes.len()
+        && groups.iter().all(|group| boxes.contains(&**group))
+        && game.constraints().len() == game.houses().len()
+        && !game.is_chaos()
+}
+
+/// Returns all column orders obtained by permuting the stacks and the columns within each
+/// stack. Position `i` of an order holds the original column shown in column `i`.
+fn column_orders() -> Vec<[usize; 9]> {
+    let permutations = [
+        [0, 1, 2],
+        [0, 2, 1],
+        [1, 0, 2],
+        [1, 2, 0],
+        [2, 0, 1],
+        [2, 1, 0],
+    ];
+
+    let mut orders = Vec::with_capacity(6 * 6 * 6 * 6);
+    for stacks in permutations {
+        for first in permutations {
+            for second in permutations {
+                for third in permutations {
+                    let within = [first, second, third];
+                    let mut order = [0; 9];
+                    for (position, column) in order.iter_mut().enumerate() {
+                        *column = stacks[position / 3] * 3 + within[position / 3][position % 3];
+                    }
+                    orders.push(order);
+                }
+            }
+        }
+    }
+    orders
+}
+
+/// A branch and bound search for the minimal row order, given a column order.
+struct Search {
+    grid: Grid,
+    columns: [usize; 9],
+    /// The smallest rows found so far. Rows below a row that was just improved upon
+    /// are reset to `u8::MAX`, so that any completion improves upon them.
+    best: Grid,
+}
+
+impl Search {
+    /// Selects the row shown at `depth`, given the rows above it.
+    ///
+    /// `used` is a bit mask of the rows already shown, `labels` maps each digit to its
+    /// label (or zero if it has not appeared yet) and `next_label` is the next free label.
+    fn rows(
+        &mut self,
+        depth: usize,
+        used: u16,
+        rows: &mut [usize; 9],
+        labels: [u8; 10],
+        next_label: u8,
+    ) {
+        if depth == 9 {
+            return;
+        }
+
+        for row in 0..9 {
+            if used & (1 << row) != 0 || !self.may_follow(depth, row, rows) {
+                continue;
+            }
+
+            let mut labels = labels;
+            let mut next_label = next_label;
+            let mut values = [0; 9];
+            for (value, &column) in values.iter_mut().zip(self.columns.iter()) {
+                let digit = self.grid[row][column] as usize;
+                if digit != 0 && labels[digit] == 0 {
+                    labels[digit] = next_label;
+                    next_label += 1;
+                }
+                *value = labels[digit];
+            }
+
+            if values > self.best[depth] {
+                continue;
+            }
+
+            if values < self.best[depth] {
+                self.best[depth] = values;
+                for below in self.best[depth + 1..].iter_mut() {
+                    *below = [u8::MAX; 9];
+                }
+            }
+
+            rows[depth] = row;
+            self.rows(depth + 1, used | (1 << row), rows, labels, next_label);
+        }
+    }
+
+    /// Determines whether `row` may be shown at `depth`: the first row of a band may be taken
+    /// from any band, the others must stem from the same band as the first.
+    fn may_follow(&self, depth: usize, row: usize, rows: &[usize; 9]) -> bool {
+        depth.is_multiple_of(3) || row / 3 == rows[depth - depth % 3] / 3
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::count_solutions;
+    use crate::transform::Transform;
+    use crate::GameState;
+
+    #[test]
+    fn equivalent_games_share_canonical_form() {
+        let game = Game::new_example();
+        let canonical = canonicalize(&game).unwrap();
+
+        for seed in 0..10 {
+            let transformed = Transform::random(seed).game(&game).unwrap();
+            let other = canonicalize(&transformed).unwrap();
+
+            assert_eq!(other.fork_state().values(), canonical.fork_state().values());
+            assert_eq!(fingerprint(&transformed), fingerprint(&game));
+        }
+    }
+
+    #[test]
+    fn canonical_form_is_equivalent() {
+        let canonical = canonicalize(&Game::new_example()).unwrap();
+        let again = canonicalize(&canonical).unwrap();
+
+        assert_eq!(again.fork_state().values(), canonical.fork_state().values());
+        assert_eq!(count_solutions(&GameState::new(canonical), 2), 1);
+    }
+
+    #[test]
+    fn different_games_differ() {
+        let a = fingerprint(&Game::new_example()).unwrap();
+        let b = fingerprint(&Game::new(
+            "4.....8.5.3..........7......2.....6.....8.4......1.......6.3.7.5..2.....1.4......",
+        ))
+        .unwrap();
+
+        assert_ne!(a, b);
+        assert_eq!(format!("{}", a).len(), 16);
+    }
+
+    #[test]
+    fn rejects_irregular_layouts() {
+        assert!(canonicalize(&Game::new_example_nonomino()).is_none());
+        assert!(fingerprint(&Game::new_example_hypersudoku()).is_none());
+        assert!(canonicalize(&Game::new_example_killer()).is_none());
+    }
+}
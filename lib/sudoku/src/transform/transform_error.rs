@@ -0,0 +1,60 @@
+use crate::game::prelude::*;
+use crate::game::LayoutError;
+use std::fmt::{Display, Formatter};
+
+/// Describes why a game could not be transformed by
+/// [`Transform::game`](crate::transform::Transform::game).
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TransformError {
+    /// The transformed groups do not form a valid layout.
+    Layout(LayoutError),
+    /// The digits are relabeled, but the clues of the game depend on their values, e.g. the
+    /// sum of a cage.
+    RelabeledDigits,
+    /// The transform is not a symmetry of the square, but a rule of the game depends on the
+    /// neighborhood of the cells, e.g. the anti-knight rule.
+    NotASymmetry,
+    /// The cells of a marker or sign between adjacent cells are no longer adjacent.
+    NotAdjacent(Index, Index),
+    /// The game has custom constraints, which cannot be moved along with the board.
+    CustomConstraints,
+    /// The cells of a clue's row, column or diagonal are no longer in line or in order.
+    BrokenLine,
+}
+
+impl From<LayoutError> for TransformError {
+    fn from(error: LayoutError) -> Self {
+        TransformError::Layout(error)
+    }
+}
+
+impl Display for TransformError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TransformError::Layout(error) => error.fmt(f),
+            TransformError::RelabeledDigits => {
+                write!(f, "relabeling the digits changes the clues of the game")
+            }
+            TransformError::NotASymmetry => {
+                write!(f, "the rules of the game require a symmetry of the square")
+            }
+            TransformError::NotAdjacent(a, b) => write!(
+                f,
+                "the marker or sign between {} and {} no longer separates adjacent cells",
+                cell_name(*a, 9),
+                cell_name(*b, 9)
+            ),
+            TransformError::CustomConstraints => {
+                write!(
+                    f,
+                    "the custom constraints of the game cannot be transformed"
+                )
+            }
+            TransformError::BrokenLine => {
+                write!(f, "a clue's row, column or diagonal is no longer in line")
+            }
+        }
+    }
+}
+
+impl std::error::Error for TransformError {}
@@ -1,7 +1,15 @@
+mod ascii_arrow;
 mod ascii_board;
+mod ascii_cage;
 mod ascii_group;
+mod ascii_little_killer;
+mod ascii_outside;
 
 pub mod ascii {
+    pub use crate::visualization::ascii_arrow::AsciiArrowPrinter;
     pub use crate::visualization::ascii_board::AsciiBoardPrinter;
+    pub use crate::visualization::ascii_cage::AsciiCagePrinter;
     pub use crate::visualization::ascii_group::AsciiGroupPrinter;
+    pub use crate::visualization::ascii_little_killer::AsciiLittleKillerPrinter;
+    pub use crate::visualization::ascii_outside::AsciiOutsidePrinter;
 }
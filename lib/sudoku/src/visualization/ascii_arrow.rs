@@ -0,0 +1,110 @@
+use crate::prelude::*;
+use crate::{Arrow, GameState};
+use std::collections::HashSet;
+use visitor::prelude::*;
+
+/// Prints the board with its arrows. Circles are shown in parentheses, e.g. `(9)`,
+/// and the cells of a shaft are linked by `-`, `|`, `\` and `/`.
+#[derive(Default)]
+pub struct AsciiArrowPrinter {}
+
+impl AsciiArrowPrinter {
+    pub fn new() -> AsciiArrowPrinter {
+        AsciiArrowPrinter {}
+    }
+}
+
+impl Visitor<GameState> for AsciiArrowPrinter {
+    type Result = ();
+
+    fn visit(&self, data: &GameState) -> Self::Result {
+        print!("{}", render(data));
+    }
+}
+
+fn render(data: &GameState) -> String {
+    let links = links(data);
+    let linked = |a: usize, b: usize| links.contains(&(a.min(b), a.max(b)));
+    let circles: HashSet<usize> = data
+        .game
+        .constraints_of::<Arrow>()
+        .map(|arrow| arrow.circle as usize)
+        .collect();
+
+    let mut output = String::new();
+    for y in 0..9 {
+        for x in 0..9 {
+            let value = match data.cell(x, y) {
+                None => "·".to_string(),
+                Some(value) => value.to_string(),
+            };
+            match circles.contains(&index(x, y, 9)) {
+                true => output.push_str(&format!("({})", value)),
+                false => output.push_str(&format!(" {} ", value)),
+            }
+
+            if x < 8 {
+                let horizontal = linked(index(x, y, 9), index(x + 1, y, 9));
+                output.push(if horizontal { '-' } else { ' ' });
+            }
+        }
+        output.truncate(output.trim_end().len());
+        output.push('\n');
+
+        if y == 8 {
+            break;
+        }
+
+        let mut line = String::new();
+        for x in 0..9 {
+            let vertical = linked(index(x, y, 9), index(x, y + 1, 9));
+            line.push_str(if vertical { " | " } else { "   " });
+
+            if x < 8 {
+                let falling = linked(index(x, y, 9), index(x + 1, y + 1, 9));
+                let rising = linked(index(x + 1, y, 9), index(x, y + 1, 9));
+                line.push(match (falling, rising) {
+                    (true, true) => 'X',
+                    (true, false) => '\\',
+                    (false, true) => '/',
+                    (false, false) => ' ',
+                });
+            }
+        }
+        output.push_str(line.trim_end());
+        output.push('\n');
+    }
+    output
+}
+
+/// Collects the pairs of consecutive cells along the arrows, from the circle to the tip.
+fn links(data: &GameState) -> HashSet<(usize, usize)> {
+    let mut links = HashSet::new();
+    for arrow in data.game.constraints_of::<Arrow>() {
+        let mut previous = arrow.circle as usize;
+        for &index in arrow.shaft.iter() {
+            let index = index as usize;
+            links.insert((previous.min(index), previous.max(index)));
+            previous = index;
+        }
+    }
+    links
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Game;
+
+    #[test]
+    fn renders_circles_and_shafts() {
+        let output = render(&GameState::new(Game::new_example_arrow()));
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines.len(), 9 * 2 - 1);
+        assert!(lines[0].starts_with(" ·   3 "));
+        // The arrow from r3c3 runs through r3c4, r2c4 and r3c5.
+        assert!(lines[4].starts_with(" ·   ·  (·)-"));
+        assert_eq!(&lines[3][12..16], " | \\");
+    }
+}
@@ -0,0 +1,104 @@
+use crate::game::Game;
+use crate::prelude::*;
+use crate::{Cage, GameState};
+use visitor::prelude::*;
+
+/// Prints the board with the outlines of its killer cages. The sum of each cage
+/// is shown in its top-left cell.
+#[derive(Default)]
+pub struct AsciiCagePrinter {}
+
+impl AsciiCagePrinter {
+    pub fn new() -> AsciiCagePrinter {
+        AsciiCagePrinter {}
+    }
+}
+
+impl Visitor<GameState> for AsciiCagePrinter {
+    type Result = ();
+
+    fn visit(&self, data: &GameState) -> Self::Result {
+        print!("{}", render(data));
+    }
+}
+
+fn render(data: &GameState) -> String {
+    let cage_of = cage_lookup(&data.game);
+    let separated = |a: usize, b: usize| cage_of[a].is_none() || cage_of[a] != cage_of[b];
+
+    let mut output = String::new();
+    for y in 0..9 {
+        for x in 0..9 {
+            let border = y == 0 || separated(index(x, y - 1, 9), index(x, y, 9));
+            output.push('+');
+            output.push_str(if border { "---" } else { "   " });
+        }
+        output.push_str("+\n");
+
+        for line in 0..2 {
+            for x in 0..9 {
+                let border = x == 0 || separated(index(x - 1, y, 9), index(x, y, 9));
+                output.push(if border { '|' } else { ' ' });
+                output.push_str(&cell_line(data, &cage_of, x, y, line));
+            }
+            output.push_str("|\n");
+        }
+    }
+
+    output.push_str(&"+---".repeat(9));
+    output.push_str("+\n");
+    output
+}
+
+/// Renders the sum of a cage in its first cell, or the value of a cell.
+fn cell_line(
+    data: &GameState,
+    cage_of: &[Option<usize>],
+    x: usize,
+    y: usize,
+    line: usize,
+) -> String {
+    let index = index(x, y, 9);
+    if line == 0 {
+        let cage = cage_of[index].and_then(|cage| data.game.constraints_of::<Cage>().nth(cage));
+        return match cage {
+            Some(cage) if cage.cells.iter().next() == Some(index as Index) => {
+                format!("{:<3}", cage.sum)
+            }
+            _ => "   ".to_string(),
+        };
+    }
+
+    match data.cell(x, y) {
+        None => " · ".to_string(),
+        Some(value) => format!(" {} ", value),
+    }
+}
+
+fn cage_lookup(game: &Game) -> Vec<Option<usize>> {
+    let mut cage_of = vec![None; 81];
+    for (id, cage) in game.constraints_of::<Cage>().enumerate() {
+        for index in cage.cells.iter() {
+            cage_of[index as usize] = Some(id);
+        }
+    }
+    cage_of
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_cage_outlines() {
+        let output = render(&GameState::new(Game::new_example_killer()));
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines.len(), 9 * 3 + 1);
+        assert_eq!(lines[0], "+---".repeat(9) + "+");
+        assert!(lines[1].starts_with("|12 |7      |13     |20 "));
+        assert!(lines[2].starts_with("| · | ·   · |"));
+        // The first cage extends downwards, so it has no border below its first cell.
+        assert!(lines[3].starts_with("+   +---+"));
+    }
+}
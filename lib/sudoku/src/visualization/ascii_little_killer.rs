@@ -0,0 +1,78 @@
+use crate::{Diagonal, GameState, LittleKiller};
+use std::collections::HashMap;
+use visitor::prelude::*;
+
+/// Prints the board with its little killer clues in the ring of positions around it. Each
+/// clue shows its sum and an arrow along its diagonal, e.g. `13↘`.
+#[derive(Default)]
+pub struct AsciiLittleKillerPrinter {}
+
+impl AsciiLittleKillerPrinter {
+    pub fn new() -> AsciiLittleKillerPrinter {
+        AsciiLittleKillerPrinter {}
+    }
+}
+
+impl Visitor<GameState> for AsciiLittleKillerPrinter {
+    type Result = ();
+
+    fn visit(&self, data: &GameState) -> Self::Result {
+        print!("{}", render(data));
+    }
+}
+
+fn render(data: &GameState) -> String {
+    let mut labels = HashMap::new();
+    for clue in data.game.constraints_of::<LittleKiller>() {
+        let (dx, dy) = clue.direction.step();
+        let (x, y) = ((clue.start % 9) as isize, (clue.start / 9) as isize);
+        let arrow = match clue.direction {
+            Diagonal::DownRight => '↘',
+            Diagonal::DownLeft => '↙',
+            Diagonal::UpRight => '↗',
+            Diagonal::UpLeft => '↖',
+        };
+        labels.insert((x - dx, y - dy), format!("{}{}", clue.sum, arrow));
+    }
+
+    let mut output = String::new();
+    for y in -1..=9isize {
+        let mut line = String::new();
+        for x in -1..=9isize {
+            if (0..9).contains(&x) && (0..9).contains(&y) {
+                match data.cell(x as usize, y as usize) {
+                    None => line.push_str(" · "),
+                    Some(value) => line.push_str(&format!(" {} ", value)),
+                }
+            } else {
+                let label = labels.get(&(x, y)).map_or("", |label| label.as_str());
+                line.push_str(&format!("{:^3}", label));
+            }
+        }
+        output.push_str(line.trim_end());
+        output.push('\n');
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Game, LittleKiller};
+
+    #[test]
+    fn renders_clues_around_the_board() {
+        let mut game = Game::new_empty();
+        game.add_constraints([
+            LittleKiller::new(1, Diagonal::DownRight, 40),
+            LittleKiller::new(79, Diagonal::UpLeft, 12),
+        ]);
+        let output = render(&GameState::new(game));
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines.len(), 11);
+        assert_eq!(lines[0], "   40↘");
+        assert!(lines[1].starts_with("    ·  · "));
+        assert_eq!(lines[10], format!("{}12↖", " ".repeat(3 * 9)));
+    }
+}
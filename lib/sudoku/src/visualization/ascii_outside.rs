@@ -0,0 +1,108 @@
+use crate::{GameState, OutsideClue, OutsideKind, Side};
+use visitor::prelude::*;
+
+/// Prints the board with its outside clues around it. Sandwich clues are shown as plain sums,
+/// X-sum clues with a leading `x`, e.g. `x24`, and skyscraper clues with a leading `#`.
+#[derive(Default)]
+pub struct AsciiOutsidePrinter {}
+
+impl AsciiOutsidePrinter {
+    pub fn new() -> AsciiOutsidePrinter {
+        AsciiOutsidePrinter {}
+    }
+}
+
+impl Visitor<GameState> for AsciiOutsidePrinter {
+    type Result = ();
+
+    fn visit(&self, data: &GameState) -> Self::Result {
+        print!("{}", render(data));
+    }
+}
+
+fn render(data: &GameState) -> String {
+    let (top, bottom) = (lanes(data, Side::Top), lanes(data, Side::Bottom));
+    let (left, right) = (lanes(data, Side::Left), lanes(data, Side::Right));
+    let margin = " ".repeat(4 * left.len());
+
+    let mut lines = Vec::new();
+    let columns = |lane: &[String; 9]| {
+        let labels: String = lane.iter().map(|label| format!("{:^3}", label)).collect();
+        format!("{}{}", margin, labels)
+    };
+    lines.extend(top.iter().rev().map(columns));
+
+    for y in 0..9 {
+        let mut line = String::new();
+        for lane in left.iter().rev() {
+            line.push_str(&format!("{:>3} ", lane[y]));
+        }
+        for x in 0..9 {
+            match data.cell(x, y) {
+                None => line.push_str(" · "),
+                Some(value) => line.push_str(&format!(" {} ", value)),
+            }
+        }
+        for lane in right.iter() {
+            line.push_str(&format!(" {:<3}", lane[y]));
+        }
+        lines.push(line);
+    }
+
+    lines.extend(bottom.iter().map(columns));
+
+    let mut output = String::new();
+    for line in lines {
+        output.push_str(line.trim_end());
+        output.push('\n');
+    }
+    output
+}
+
+/// Returns the labels of the clues on a side, one lane per kind of clue present so that
+/// clues of both kinds can share a row or column. The first lane is closest to the board.
+fn lanes(data: &GameState, side: Side) -> Vec<[String; 9]> {
+    let mut lanes = Vec::new();
+    for kind in [
+        OutsideKind::Sandwich,
+        OutsideKind::XSum,
+        OutsideKind::Skyscraper,
+    ] {
+        let clues: Vec<&OutsideClue> = data
+            .game
+            .constraints_of::<OutsideClue>()
+            .filter(|clue| clue.side == side && clue.kind == kind)
+            .collect();
+        if clues.is_empty() {
+            continue;
+        }
+
+        let mut lane: [String; 9] = Default::default();
+        for clue in clues {
+            lane[clue.line] = match kind {
+                OutsideKind::Sandwich => clue.sum.to_string(),
+                OutsideKind::XSum => format!("x{}", clue.sum),
+                OutsideKind::Skyscraper => format!("#{}", clue.sum),
+            };
+        }
+        lanes.push(lane);
+    }
+    lanes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Game;
+
+    #[test]
+    fn renders_clues_around_the_board() {
+        let output = render(&GameState::new(Game::new_example_sandwich()));
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines.len(), 1 + 9);
+        assert_eq!(lines[0], "        7    18 20 14 35 12 15");
+        assert!(lines[1].starts_with("     ·  ·  ·  ·  7 "));
+        assert!(lines[8].starts_with("  0  · "));
+    }
+}